@@ -33,6 +33,46 @@ pub struct Args {
     #[arg(short, long)]
     pub verbose: bool,
 
+    /// Follow only the first parent of merges when walking history
+    #[arg(long)]
+    pub first_parent: bool,
+
+    /// Also load tables from every initialized submodule (recursively)
+    #[arg(long)]
+    pub recurse_submodules: bool,
+
+    /// Include ignored files in the status table
+    #[arg(long)]
+    pub include_ignored: bool,
+
+    /// Base branch for the branch_report table (defaults to origin/HEAD or main/master)
+    #[arg(long, value_name = "BRANCH")]
+    pub base: Option<String>,
+
+    /// Extra commit_references pattern as KIND=REGEX (can be specified multiple times)
+    #[arg(long = "reference-pattern", value_name = "KIND=REGEX")]
+    pub reference_patterns: Vec<String>,
+
+    /// Revision to read the codeowners table from (defaults to HEAD)
+    #[arg(long, value_name = "REV")]
+    pub codeowners_rev: Option<String>,
+
+    /// Extra todos marker besides TODO/FIXME/HACK/XXX (can be specified multiple times)
+    #[arg(long = "todo-tag", value_name = "TAG")]
+    pub todo_tags: Vec<String>,
+
+    /// Revision to scan for the todos table (defaults to HEAD)
+    #[arg(long, value_name = "REV")]
+    pub todos_rev: Option<String>,
+
+    /// Fewest commits two files must share to appear in file_coupling (default 2)
+    #[arg(long, value_name = "N")]
+    pub coupling_min_count: Option<usize>,
+
+    /// Skip commits touching more than N files in file_coupling (default 50)
+    #[arg(long, value_name = "N")]
+    pub coupling_max_files: Option<usize>,
+
     #[command(subcommand)]
     pub command: Option<Command>,
 }
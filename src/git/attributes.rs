@@ -0,0 +1,219 @@
+use crate::git::pattern::PathPattern;
+use crate::git::{common_dir, global_config_file};
+use git2::{ObjectType, Oid, Repository, Tree};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// One attributes file and where it came from.
+pub struct AttributesFile {
+    pub source: String,
+    /// global, tree or info
+    pub scope: &'static str,
+    /// Directory the patterns are relative to ("" for the repository root)
+    pub base: String,
+    pub contents: String,
+}
+
+/// Attributes files in increasing precedence: `core.attributesFile`, the
+/// `.gitattributes` blobs of `tree` from the root downwards, then
+/// `info/attributes`.
+pub fn attributes_files(
+    repo: &Repository,
+    tree: Option<&Tree>,
+) -> Result<Vec<AttributesFile>, git2::Error> {
+    let tree_files = match tree {
+        Some(tree) => AttributesFinder::default().find(repo, tree)?,
+        None => Rc::from([]),
+    };
+    Ok(attributes_files_with(repo, &tree_files))
+}
+
+/// Like `attributes_files`, with the tree's `.gitattributes` blobs already
+/// found as `(directory, blob)` pairs.
+fn attributes_files_with(repo: &Repository, tree_files: &[(String, Oid)]) -> Vec<AttributesFile> {
+    let mut files = Vec::new();
+
+    if let Some(path) = global_config_file(repo, "core.attributesFile", "attributes") {
+        if let Ok(contents) = std::fs::read_to_string(&path) {
+            files.push(AttributesFile {
+                source: path.display().to_string(),
+                scope: "global",
+                base: String::new(),
+                contents,
+            });
+        }
+    }
+
+    let mut from_tree: Vec<AttributesFile> = tree_files
+        .iter()
+        .filter_map(|(dir, id)| {
+            let blob = repo.find_blob(*id).ok()?;
+            Some(AttributesFile {
+                source: format!("{}.gitattributes", dir),
+                scope: "tree",
+                base: dir.clone(),
+                contents: String::from_utf8_lossy(blob.content()).to_string(),
+            })
+        })
+        .collect();
+    // Deeper files override shallower ones
+    from_tree.sort_by_key(|f| (f.base.matches('/').count(), f.base.clone()));
+    files.extend(from_tree);
+
+    let info_path = common_dir(repo).join("info").join("attributes");
+    if let Ok(contents) = std::fs::read_to_string(&info_path) {
+        files.push(AttributesFile {
+            source: info_path.display().to_string(),
+            scope: "info",
+            base: String::new(),
+            contents,
+        });
+    }
+
+    files
+}
+
+/// Finds the `.gitattributes` blobs of many trees. Results are kept per
+/// subtree, so walking history only visits the directories each commit changed.
+#[derive(Default)]
+pub struct AttributesFinder {
+    subtrees: HashMap<Oid, Rc<[(String, Oid)]>>,
+}
+
+impl AttributesFinder {
+    /// `(directory, blob)` for every `.gitattributes` in `tree`, with
+    /// directories written like tree-walk prefixes ("" or "src/").
+    pub fn find(
+        &mut self,
+        repo: &Repository,
+        tree: &Tree,
+    ) -> Result<Rc<[(String, Oid)]>, git2::Error> {
+        if let Some(found) = self.subtrees.get(&tree.id()) {
+            return Ok(found.clone());
+        }
+        let mut found = Vec::new();
+        for entry in tree.iter() {
+            match entry.kind() {
+                Some(ObjectType::Blob) if entry.name() == Some(".gitattributes") => {
+                    found.push((String::new(), entry.id()));
+                }
+                Some(ObjectType::Tree) => {
+                    let name = entry.name().unwrap_or("");
+                    let subtree = repo.find_tree(entry.id())?;
+                    for (dir, id) in self.find(repo, &subtree)?.iter() {
+                        found.push((format!("{}/{}", name, dir), *id));
+                    }
+                }
+                _ => {}
+            }
+        }
+        let found: Rc<[(String, Oid)]> = found.into();
+        self.subtrees.insert(tree.id(), found.clone());
+        Ok(found)
+    }
+}
+
+/// Split an attributes line into its pattern and `(name, value)` pairs, where
+/// value follows `git check-attr`: set, unset, unspecified or the string.
+pub fn parse_attributes_line(line: &str) -> Option<(String, Vec<(String, String)>)> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+
+    let (pattern, rest) = if let Some(quoted) = line.strip_prefix('"') {
+        let end = quoted.find('"')?;
+        (quoted[..end].to_string(), &quoted[end + 1..])
+    } else {
+        let end = line.find(char::is_whitespace).unwrap_or(line.len());
+        (line[..end].to_string(), &line[end..])
+    };
+
+    let attributes = rest
+        .split_whitespace()
+        .map(|token| {
+            if let Some(name) = token.strip_prefix('-') {
+                (name.to_string(), "unset".to_string())
+            } else if let Some(name) = token.strip_prefix('!') {
+                (name.to_string(), "unspecified".to_string())
+            } else if let Some((name, value)) = token.split_once('=') {
+                (name.to_string(), value.to_string())
+            } else {
+                (token.to_string(), "set".to_string())
+            }
+        })
+        .collect();
+
+    Some((pattern, attributes))
+}
+
+struct AttributeRule {
+    base: String,
+    pattern: PathPattern,
+    attributes: Vec<(String, String)>,
+}
+
+/// Attributes as they stood in one tree, for looking up paths of an older
+/// revision without the work tree or index getting in the way.
+pub struct TreeAttributes {
+    /// Lowest precedence first
+    rules: Vec<AttributeRule>,
+}
+
+impl TreeAttributes {
+    pub fn load(repo: &Repository, tree: &Tree) -> Result<Self, git2::Error> {
+        let tree_files = AttributesFinder::default().find(repo, tree)?;
+        Ok(Self::from_tree_files(repo, &tree_files))
+    }
+
+    /// Attributes for a tree whose `.gitattributes` blobs were found with
+    /// `AttributesFinder`.
+    pub fn from_tree_files(repo: &Repository, tree_files: &[(String, Oid)]) -> Self {
+        let mut rules = Vec::new();
+        for file in attributes_files_with(repo, tree_files) {
+            for line in file.contents.lines() {
+                let Some((pattern, attributes)) = parse_attributes_line(line) else {
+                    continue;
+                };
+                // Macro definitions don't name paths, and git rejects negated patterns
+                if pattern.starts_with("[attr]") {
+                    continue;
+                }
+                let Some(pattern) = PathPattern::parse(&pattern).filter(|p| !p.negated) else {
+                    continue;
+                };
+                rules.push(AttributeRule {
+                    base: file.base.clone(),
+                    pattern,
+                    attributes,
+                });
+            }
+        }
+        Self { rules }
+    }
+
+    /// Value of `name` for the file at `path` in `git check-attr` terms:
+    /// "set", "unset" or the string. `None` when unspecified.
+    pub fn get(&self, path: &str, name: &str) -> Option<&str> {
+        for rule in self.rules.iter().rev() {
+            let Some(relative) = path.strip_prefix(rule.base.as_str()) else {
+                continue;
+            };
+            if !rule.pattern.matches(relative, false) {
+                continue;
+            }
+            let value = rule
+                .attributes
+                .iter()
+                .rev()
+                .find(|(attribute, _)| attribute == name)
+                .map(|(_, value)| value.as_str());
+            match value {
+                Some("unspecified") => return None,
+                Some(value) => return Some(value),
+                None => {}
+            }
+        }
+        None
+    }
+}
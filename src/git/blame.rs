@@ -0,0 +1,86 @@
+use git2::{BlameOptions, ObjectType, Oid, Repository, Time, TreeWalkMode, TreeWalkResult};
+use std::path::Path;
+
+/// One line of a file and the commit that last changed it.
+pub struct BlameLine {
+    /// 1-based
+    pub line_number: usize,
+    pub commit_id: Oid,
+    /// Line number in `original_path` at `commit_id`
+    pub original_line: usize,
+    pub original_path: String,
+    pub author_name: String,
+    pub author_email: String,
+    pub authored_at: Time,
+    pub content: String,
+}
+
+/// Paths of all blobs in the HEAD tree; empty for an unborn HEAD.
+pub fn head_paths(repo: &Repository) -> Result<Vec<String>, git2::Error> {
+    let mut paths = Vec::new();
+    let Ok(tree) = repo.head().and_then(|head| head.peel_to_tree()) else {
+        return Ok(paths);
+    };
+    tree.walk(TreeWalkMode::PreOrder, |dir, entry| {
+        if entry.kind() == Some(ObjectType::Blob) {
+            paths.push(format!("{}{}", dir, entry.name().unwrap_or("")));
+        }
+        TreeWalkResult::Ok
+    })?;
+    Ok(paths)
+}
+
+/// Blame `path` line by line as of `commit` (HEAD when `None`). Authors are
+/// resolved through `.mailmap` when `use_mailmap` is set. Returns `None` when
+/// the file cannot be blamed.
+pub fn blame_lines(
+    repo: &Repository,
+    path: &str,
+    commit: Option<Oid>,
+    use_mailmap: bool,
+) -> Option<Vec<BlameLine>> {
+    let mut opts = BlameOptions::new();
+    opts.use_mailmap(use_mailmap);
+    if let Some(commit) = commit {
+        opts.newest_commit(commit);
+    }
+    let blame = repo.blame_file(Path::new(path), Some(&mut opts)).ok()?;
+
+    // Line text comes from the blob at that commit; binary files have none
+    let tree = match commit {
+        Some(commit) => repo.find_commit(commit).and_then(|c| c.tree()),
+        None => repo.head().and_then(|head| head.peel_to_tree()),
+    };
+    let content = tree
+        .and_then(|tree| tree.get_path(Path::new(path)))
+        .and_then(|entry| repo.find_blob(entry.id()))
+        .ok()
+        .filter(|blob| !blob.is_binary())
+        .map(|blob| String::from_utf8_lossy(blob.content()).to_string())
+        .unwrap_or_default();
+    let text: Vec<&str> = content.lines().collect();
+
+    let mut lines = Vec::new();
+    for hunk in blame.iter() {
+        let sig = hunk.final_signature();
+        let original_path = hunk
+            .path()
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_else(|| path.to_string());
+
+        for offset in 0..hunk.lines_in_hunk() {
+            let line_number = hunk.final_start_line() + offset;
+            lines.push(BlameLine {
+                line_number,
+                commit_id: hunk.final_commit_id(),
+                original_line: hunk.orig_start_line() + offset,
+                original_path: original_path.clone(),
+                author_name: sig.name().unwrap_or("").to_string(),
+                author_email: sig.email().unwrap_or("").to_string(),
+                authored_at: sig.when(),
+                content: text.get(line_number - 1).unwrap_or(&"").to_string(),
+            });
+        }
+    }
+    Some(lines)
+}
@@ -0,0 +1,110 @@
+use crate::git::pattern::PathPattern;
+use git2::{Oid, Repository};
+use std::path::Path;
+
+/// Where GitHub looks for the file, in order; only the first one found is used.
+pub const CODEOWNERS_LOCATIONS: &[&str] = &[".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+pub struct CodeownersRule {
+    pub line_number: usize,
+    pub text: String,
+    /// Users, teams or emails; empty when the line removes ownership
+    pub owners: Vec<String>,
+    pattern: PathPattern,
+}
+
+/// The CODEOWNERS file in effect at `rev` (HEAD when `None`): its path and
+/// blob id, or `None` when the revision has none.
+pub fn find_codeowners(
+    repo: &Repository,
+    rev: Option<&str>,
+) -> Result<Option<(&'static str, Oid)>, git2::Error> {
+    let tree = repo
+        .revparse_single(rev.unwrap_or("HEAD"))?
+        .peel_to_tree()?;
+    Ok(CODEOWNERS_LOCATIONS.iter().find_map(|location| {
+        tree.get_path(Path::new(location))
+            .ok()
+            .filter(|entry| entry.kind() == Some(git2::ObjectType::Blob))
+            .map(|entry| (*location, entry.id()))
+    }))
+}
+
+/// Parse CODEOWNERS lines. Patterns are always relative to the repository
+/// root, whichever directory the file lives in; `!` negation is not allowed.
+pub fn parse_codeowners(contents: &str) -> Vec<CodeownersRule> {
+    let mut rules = Vec::new();
+    for (line_idx, line) in contents.lines().enumerate() {
+        let tokens = split_tokens(line);
+        let Some((pattern_text, rest)) = tokens.split_first() else {
+            continue;
+        };
+        if pattern_text.starts_with('#') || pattern_text.starts_with('!') {
+            continue;
+        }
+        let Some(pattern) = PathPattern::parse(pattern_text) else {
+            continue;
+        };
+        let owners = rest
+            .iter()
+            .take_while(|owner| !owner.starts_with('#'))
+            .cloned()
+            .collect();
+        rules.push(CodeownersRule {
+            line_number: line_idx + 1,
+            text: pattern_text.clone(),
+            owners,
+            pattern,
+        });
+    }
+    rules
+}
+
+/// The last rule matching `path`, which alone decides its owners.
+pub fn matching_owners<'a>(rules: &'a [CodeownersRule], path: &str) -> Option<&'a CodeownersRule> {
+    rules.iter().rev().find(|rule| rule.matches(path))
+}
+
+impl CodeownersRule {
+    /// A pattern naming a directory owns everything below it, except that a
+    /// trailing `/*` only reaches the directory's direct children.
+    pub fn matches(&self, path: &str) -> bool {
+        if self.pattern.matches(path, false) {
+            return true;
+        }
+        if self.text.ends_with("/*") {
+            return false;
+        }
+        path.match_indices('/')
+            .any(|(i, _)| self.pattern.matches(&path[..i], true))
+    }
+}
+
+/// Split on unescaped whitespace, keeping `\ ` in a pattern as a space.
+fn split_tokens(line: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(' ') => current.push_str("\\ "),
+                Some(next) => {
+                    current.push('\\');
+                    current.push(next);
+                }
+                None => current.push('\\'),
+            },
+            c if c.is_whitespace() => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    tokens
+}
@@ -0,0 +1,213 @@
+use git2::Oid;
+use std::fs;
+use std::path::Path;
+
+const SIGNATURE: &[u8; 4] = b"CGPH";
+const HASH_LEN: usize = 20;
+const CHUNK_FANOUT: &[u8; 4] = b"OIDF";
+const CHUNK_OID_LOOKUP: &[u8; 4] = b"OIDL";
+const CHUNK_COMMIT_DATA: &[u8; 4] = b"CDAT";
+const CHUNK_EXTRA_EDGES: &[u8; 4] = b"EDGE";
+/// Bytes per CDAT entry: tree id, two parent positions, generation and date
+const COMMIT_DATA_LEN: usize = HASH_LEN + 16;
+const PARENT_NONE: u32 = 0x7000_0000;
+const PARENT_EXTRA: u32 = 0x8000_0000;
+/// Levels are capped here, and 0 means the writer did not compute them
+const GENERATION_MAX: u32 = 0x3FFF_FFFF;
+
+/// Commit data read from the commit-graph instead of the commit object.
+#[derive(Debug, Clone)]
+pub struct GraphCommit {
+    pub parent_ids: Vec<Oid>,
+    /// Committer time in seconds since the epoch
+    pub commit_time: i64,
+    /// Topological level: 1 for roots, else 1 + the highest parent level.
+    /// `None` when the file does not record a usable value
+    pub generation: Option<u32>,
+}
+
+/// One `commit-graph` file; in a split chain each layer builds on the previous.
+struct Layer {
+    data: Vec<u8>,
+    num_commits: u32,
+    /// Graph position of this layer's first commit
+    base_position: u32,
+    fanout: usize,
+    oid_lookup: usize,
+    commit_data: usize,
+    extra_edges: Option<usize>,
+}
+
+/// Reader for `objects/info/commit-graph` and `objects/info/commit-graphs/`,
+/// the precomputed topology git keeps to avoid parsing commit objects.
+pub struct CommitGraph {
+    /// Base layer first
+    layers: Vec<Layer>,
+}
+
+impl CommitGraph {
+    /// Load the split chain if there is one, else the single file. Returns
+    /// `None` when neither exists or a file does not parse.
+    pub fn open(objects_dir: &Path) -> Option<Self> {
+        let info_dir = objects_dir.join("info");
+        let chain_file = info_dir.join("commit-graphs").join("commit-graph-chain");
+
+        let paths = match fs::read_to_string(&chain_file) {
+            Ok(chain) => chain
+                .lines()
+                .map(str::trim)
+                .filter(|hash| !hash.is_empty())
+                .map(|hash| info_dir.join("commit-graphs").join(format!("graph-{}.graph", hash)))
+                .collect(),
+            Err(_) => vec![info_dir.join("commit-graph")],
+        };
+
+        let mut layers: Vec<Layer> = Vec::new();
+        for path in paths {
+            let data = fs::read(path).ok()?;
+            let base_position = layers
+                .last()
+                .map(|l| l.base_position + l.num_commits)
+                .unwrap_or(0);
+            layers.push(Layer::parse(data, base_position)?);
+        }
+
+        if layers.is_empty() {
+            None
+        } else {
+            Some(Self { layers })
+        }
+    }
+
+    pub fn commit(&self, oid: Oid) -> Option<GraphCommit> {
+        let position = self.position(oid)?;
+        let (layer, local) = self.layer_at(position)?;
+        let entry = layer.commit_data + local as usize * COMMIT_DATA_LEN;
+        let data = &layer.data;
+
+        // The entry starts with the tree id, which topology doesn't need
+        let parent1 = read_u32(data, entry + HASH_LEN)?;
+        let parent2 = read_u32(data, entry + HASH_LEN + 4)?;
+        let level_and_time = read_u32(data, entry + HASH_LEN + 8)?;
+        let time_low = read_u32(data, entry + HASH_LEN + 12)?;
+
+        let mut parent_ids = Vec::new();
+        if parent1 != PARENT_NONE {
+            parent_ids.push(self.oid_at(parent1)?);
+        }
+        if parent2 & PARENT_EXTRA != 0 {
+            // Octopus merges list the remaining parents in the EDGE chunk
+            let mut edge = layer.extra_edges? + (parent2 & !PARENT_EXTRA) as usize * 4;
+            loop {
+                let value = read_u32(data, edge)?;
+                parent_ids.push(self.oid_at(value & !PARENT_EXTRA)?);
+                if value & PARENT_EXTRA != 0 {
+                    break;
+                }
+                edge += 4;
+            }
+        } else if parent2 != PARENT_NONE {
+            parent_ids.push(self.oid_at(parent2)?);
+        }
+
+        Some(GraphCommit {
+            parent_ids,
+            commit_time: (((level_and_time & 0x3) as i64) << 32) | time_low as i64,
+            generation: Some(level_and_time >> 2).filter(|&g| g != 0 && g < GENERATION_MAX),
+        })
+    }
+
+    fn layer_at(&self, position: u32) -> Option<(&Layer, u32)> {
+        self.layers
+            .iter()
+            .find(|l| position >= l.base_position && position < l.base_position + l.num_commits)
+            .map(|l| (l, position - l.base_position))
+    }
+
+    fn oid_at(&self, position: u32) -> Option<Oid> {
+        let (layer, local) = self.layer_at(position)?;
+        let start = layer.oid_lookup + local as usize * HASH_LEN;
+        Oid::from_bytes(layer.data.get(start..start + HASH_LEN)?).ok()
+    }
+
+    fn position(&self, oid: Oid) -> Option<u32> {
+        let bytes = oid.as_bytes();
+        self.layers.iter().find_map(|layer| {
+            let first = bytes[0] as usize;
+            let low = if first == 0 {
+                0
+            } else {
+                read_u32(&layer.data, layer.fanout + (first - 1) * 4)?
+            };
+            let high = read_u32(&layer.data, layer.fanout + first * 4)?;
+
+            let (mut low, mut high) = (low, high);
+            while low < high {
+                let mid = low + (high - low) / 2;
+                let start = layer.oid_lookup + mid as usize * HASH_LEN;
+                match layer.data.get(start..start + HASH_LEN)?.cmp(bytes) {
+                    std::cmp::Ordering::Equal => return Some(layer.base_position + mid),
+                    std::cmp::Ordering::Less => low = mid + 1,
+                    std::cmp::Ordering::Greater => high = mid,
+                }
+            }
+            None
+        })
+    }
+}
+
+impl Layer {
+    fn parse(data: Vec<u8>, base_position: u32) -> Option<Self> {
+        // Header: signature, version 1, SHA-1 hash version 1, chunk count, base graph count
+        if data.get(0..4)? != SIGNATURE || data[4] != 1 || data[5] != 1 {
+            return None;
+        }
+        let chunk_count = *data.get(6)? as usize;
+
+        let mut fanout = None;
+        let mut oid_lookup = None;
+        let mut commit_data = None;
+        let mut extra_edges = None;
+        for i in 0..chunk_count {
+            let entry = 8 + i * 12;
+            let id = data.get(entry..entry + 4)?;
+            let offset = read_u64(&data, entry + 4)? as usize;
+            if id == CHUNK_FANOUT {
+                fanout = Some(offset);
+            } else if id == CHUNK_OID_LOOKUP {
+                oid_lookup = Some(offset);
+            } else if id == CHUNK_COMMIT_DATA {
+                commit_data = Some(offset);
+            } else if id == CHUNK_EXTRA_EDGES {
+                extra_edges = Some(offset);
+            }
+        }
+
+        let fanout = fanout?;
+        let num_commits = read_u32(&data, fanout + 255 * 4)?;
+        let commit_data = commit_data?;
+        if data.len() < commit_data + num_commits as usize * COMMIT_DATA_LEN {
+            return None;
+        }
+
+        Some(Self {
+            data,
+            num_commits,
+            base_position,
+            fanout,
+            oid_lookup: oid_lookup?,
+            commit_data,
+            extra_edges,
+        })
+    }
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 4)?;
+    Some(u32::from_be_bytes(bytes.try_into().ok()?))
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Option<u64> {
+    let bytes = data.get(offset..offset + 8)?;
+    Some(u64::from_be_bytes(bytes.try_into().ok()?))
+}
@@ -0,0 +1,39 @@
+use crate::error::Result;
+use git2::{Commit, Diff, DiffOptions, Oid, Repository};
+
+/// Diff settings matching git's defaults, so hunks line up with `git diff`.
+pub fn diff_options() -> DiffOptions {
+    let mut opts = DiffOptions::new();
+    opts.indent_heuristic(true);
+    opts
+}
+
+/// `commit` diffed against each of its parents, or only the first with
+/// `first_parent`, or against the empty tree for a root commit, paired with
+/// the parent's id. The diff tables and patch-ids all go through here, so
+/// they agree on what a commit changed.
+pub fn parent_diffs<'r>(
+    repo: &'r Repository,
+    commit: &Commit,
+    first_parent: bool,
+) -> Result<Vec<(Option<Oid>, Diff<'r>)>> {
+    let tree = commit.tree()?;
+    if commit.parent_count() == 0 {
+        let diff = repo.diff_tree_to_tree(None, Some(&tree), Some(&mut diff_options()))?;
+        return Ok(vec![(None, diff)]);
+    }
+
+    let mut diffs = Vec::with_capacity(commit.parent_count());
+    let parents = if first_parent {
+        1
+    } else {
+        commit.parent_count()
+    };
+    for parent in commit.parents().take(parents) {
+        let parent_tree = parent.tree()?;
+        let diff =
+            repo.diff_tree_to_tree(Some(&parent_tree), Some(&tree), Some(&mut diff_options()))?;
+        diffs.push((Some(parent.id()), diff));
+    }
+    Ok(diffs)
+}
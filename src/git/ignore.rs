@@ -0,0 +1,146 @@
+use crate::git::pattern::PathPattern;
+use crate::git::{common_dir, global_config_file};
+use git2::Repository;
+
+pub struct IgnoreRule {
+    pub source: String,
+    pub scope: &'static str,
+    pub line_number: usize,
+    /// Directory the rule is relative to ("" for the repository root)
+    pub base: String,
+    pub text: String,
+    pub pattern: PathPattern,
+}
+
+impl IgnoreRule {
+    /// `source:line:pattern`, as printed by `git check-ignore -v`.
+    pub fn describe(&self) -> String {
+        format!("{}:{}:{}", self.source, self.line_number, self.text)
+    }
+}
+
+/// Collect ignore rules in increasing precedence: `core.excludesFile`,
+/// `info/exclude`, then the work tree's `.gitignore` files from the root
+/// downwards, as `git check-ignore` sees them.
+pub fn load_ignore_rules(repo: &Repository) -> Vec<IgnoreRule> {
+    let mut rules = Vec::new();
+
+    if let Some(path) = global_config_file(repo, "core.excludesFile", "ignore") {
+        if let Ok(contents) = std::fs::read_to_string(&path) {
+            push_rules(
+                &mut rules,
+                &path.display().to_string(),
+                "global",
+                "",
+                &contents,
+            );
+        }
+    }
+
+    let exclude_path = common_dir(repo).join("info").join("exclude");
+    if let Ok(contents) = std::fs::read_to_string(&exclude_path) {
+        push_rules(
+            &mut rules,
+            &exclude_path.display().to_string(),
+            "info",
+            "",
+            &contents,
+        );
+    }
+
+    // Every .gitignore in the work tree, tracked or not, a directory level at a
+    // time. Like git, ignored directories and nested repositories are not entered
+    let Some(workdir) = repo.workdir() else {
+        return rules;
+    };
+    let mut level = vec![String::new()];
+    while !level.is_empty() {
+        level.sort();
+        let mut next_level = Vec::new();
+        for dir in level {
+            let dir_path = workdir.join(&dir);
+            if let Ok(contents) = std::fs::read_to_string(dir_path.join(".gitignore")) {
+                push_rules(
+                    &mut rules,
+                    &format!("{}.gitignore", dir),
+                    "tree",
+                    &dir,
+                    &contents,
+                );
+            }
+            let Ok(entries) = std::fs::read_dir(&dir_path) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                if !entry.file_type().is_ok_and(|t| t.is_dir()) {
+                    continue;
+                }
+                let name = entry.file_name().to_string_lossy().to_string();
+                if name == ".git" || entry.path().join(".git").exists() {
+                    continue;
+                }
+                let child = format!("{}{}", dir, name);
+                if matching_rule(&rules, &child, true).is_some_and(|rule| !rule.pattern.negated) {
+                    continue;
+                }
+                next_level.push(format!("{}/", child));
+            }
+        }
+        level = next_level;
+    }
+
+    rules
+}
+
+fn push_rules(
+    rules: &mut Vec<IgnoreRule>,
+    source: &str,
+    scope: &'static str,
+    base: &str,
+    contents: &str,
+) {
+    for (line_idx, line) in contents.lines().enumerate() {
+        if let Some(pattern) = PathPattern::parse(line) {
+            rules.push(IgnoreRule {
+                source: source.to_string(),
+                scope,
+                line_number: line_idx + 1,
+                base: base.to_string(),
+                text: line.trim_end().to_string(),
+                pattern,
+            });
+        }
+    }
+}
+
+/// The rule deciding whether `path` is ignored, including negated rules that
+/// re-include it. A path inside an ignored directory reports the directory's rule.
+pub fn matching_rule<'a>(
+    rules: &'a [IgnoreRule],
+    path: &str,
+    is_dir: bool,
+) -> Option<&'a IgnoreRule> {
+    let path = path.trim_start_matches("./");
+    let is_dir = is_dir || path.ends_with('/');
+    let path = path.trim_end_matches('/');
+
+    // Once a parent directory is excluded nothing inside can be re-included
+    let components: Vec<&str> = path.split('/').collect();
+    for depth in 1..components.len() {
+        let dir = components[..depth].join("/");
+        if let Some(rule) = last_match(rules, &dir, true) {
+            if !rule.pattern.negated {
+                return Some(rule);
+            }
+        }
+    }
+
+    last_match(rules, path, is_dir)
+}
+
+fn last_match<'a>(rules: &'a [IgnoreRule], path: &str, is_dir: bool) -> Option<&'a IgnoreRule> {
+    rules.iter().rev().find(|rule| {
+        path.strip_prefix(rule.base.as_str())
+            .is_some_and(|relative| !relative.is_empty() && rule.pattern.matches(relative, is_dir))
+    })
+}
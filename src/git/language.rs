@@ -0,0 +1,680 @@
+use crate::git::attributes::TreeAttributes;
+use git2::{AttrCheckFlags, AttrValue, Repository, Tree};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Languages recognised by exact file name.
+const FILENAMES: &[(&str, &str)] = &[
+    ("Makefile", "Makefile"),
+    ("GNUmakefile", "Makefile"),
+    ("makefile", "Makefile"),
+    ("Dockerfile", "Dockerfile"),
+    ("Containerfile", "Dockerfile"),
+    ("CMakeLists.txt", "CMake"),
+    ("Rakefile", "Ruby"),
+    ("Gemfile", "Ruby"),
+    ("Jenkinsfile", "Groovy"),
+    ("Cargo.lock", "TOML"),
+    ("BUILD", "Starlark"),
+    ("BUILD.bazel", "Starlark"),
+    ("WORKSPACE", "Starlark"),
+    (".bashrc", "Shell"),
+    (".bash_profile", "Shell"),
+    (".zshrc", "Shell"),
+    (".profile", "Shell"),
+];
+
+/// Languages recognised by extension, compared case-insensitively.
+const EXTENSIONS: &[(&str, &str)] = &[
+    ("adoc", "AsciiDoc"),
+    ("bash", "Shell"),
+    ("bat", "Batchfile"),
+    ("c", "C"),
+    ("cc", "C++"),
+    ("cjs", "JavaScript"),
+    ("clj", "Clojure"),
+    ("cmake", "CMake"),
+    ("cmd", "Batchfile"),
+    ("cpp", "C++"),
+    ("cs", "C#"),
+    ("css", "CSS"),
+    ("csv", "CSV"),
+    ("cxx", "C++"),
+    ("dart", "Dart"),
+    ("el", "Emacs Lisp"),
+    ("erl", "Erlang"),
+    ("ex", "Elixir"),
+    ("exs", "Elixir"),
+    ("fish", "fish"),
+    ("fs", "F#"),
+    ("go", "Go"),
+    ("gradle", "Gradle"),
+    ("graphql", "GraphQL"),
+    ("groovy", "Groovy"),
+    ("h", "C"),
+    ("hh", "C++"),
+    ("hpp", "C++"),
+    ("hs", "Haskell"),
+    ("htm", "HTML"),
+    ("html", "HTML"),
+    ("hxx", "C++"),
+    ("ini", "INI"),
+    ("java", "Java"),
+    ("jl", "Julia"),
+    ("js", "JavaScript"),
+    ("json", "JSON"),
+    ("jsx", "JavaScript"),
+    ("kt", "Kotlin"),
+    ("kts", "Kotlin"),
+    ("less", "Less"),
+    ("lua", "Lua"),
+    ("m", "Objective-C"),
+    ("markdown", "Markdown"),
+    ("md", "Markdown"),
+    ("mjs", "JavaScript"),
+    ("mk", "Makefile"),
+    ("ml", "OCaml"),
+    ("mm", "Objective-C++"),
+    ("nim", "Nim"),
+    ("php", "PHP"),
+    ("pl", "Perl"),
+    ("pm", "Perl"),
+    ("proto", "Protocol Buffer"),
+    ("ps1", "PowerShell"),
+    ("py", "Python"),
+    ("r", "R"),
+    ("rb", "Ruby"),
+    ("rs", "Rust"),
+    ("rst", "reStructuredText"),
+    ("sass", "Sass"),
+    ("scala", "Scala"),
+    ("scss", "SCSS"),
+    ("sh", "Shell"),
+    ("sol", "Solidity"),
+    ("sql", "SQL"),
+    ("svelte", "Svelte"),
+    ("swift", "Swift"),
+    ("tex", "TeX"),
+    ("tf", "HCL"),
+    ("toml", "TOML"),
+    ("ts", "TypeScript"),
+    ("tsx", "TSX"),
+    ("txt", "Text"),
+    ("vim", "Vim Script"),
+    ("vue", "Vue"),
+    ("xml", "XML"),
+    ("yaml", "YAML"),
+    ("yml", "YAML"),
+    ("zig", "Zig"),
+    ("zsh", "Shell"),
+];
+
+/// Languages recognised by the interpreter named in a `#!` line.
+const INTERPRETERS: &[(&str, &str)] = &[
+    ("bash", "Shell"),
+    ("dash", "Shell"),
+    ("deno", "TypeScript"),
+    ("ksh", "Shell"),
+    ("lua", "Lua"),
+    ("node", "JavaScript"),
+    ("nodejs", "JavaScript"),
+    ("perl", "Perl"),
+    ("php", "PHP"),
+    ("python", "Python"),
+    ("Rscript", "R"),
+    ("ruby", "Ruby"),
+    ("sh", "Shell"),
+    ("zsh", "Shell"),
+];
+
+const VENDORED_DIRS: &[&str] = &[
+    "vendor",
+    "vendors",
+    "third_party",
+    "third-party",
+    "node_modules",
+    "bower_components",
+];
+const GENERATED_FILES: &[&str] = &[
+    "Cargo.lock",
+    "package-lock.json",
+    "yarn.lock",
+    "pnpm-lock.yaml",
+    "Gemfile.lock",
+    "poetry.lock",
+    "composer.lock",
+    "go.sum",
+];
+const GENERATED_SUFFIXES: &[&str] = &[
+    ".min.js",
+    ".min.css",
+    ".pb.go",
+    "_pb2.py",
+    ".pb.h",
+    ".pb.cc",
+    ".generated.ts",
+    ".g.dart",
+];
+const TEST_DIRS: &[&str] = &["test", "tests", "__tests__", "spec", "specs", "testdata"];
+const DOCS_DIRS: &[&str] = &["doc", "docs", "documentation"];
+const DOCS_FILES: &[&str] = &[
+    "README",
+    "CHANGELOG",
+    "CHANGES",
+    "CONTRIBUTING",
+    "LICENSE",
+    "COPYING",
+    "AUTHORS",
+    "NOTICE",
+];
+const DOCS_LANGUAGES: &[&str] = &["Markdown", "reStructuredText", "AsciiDoc", "TeX", "Text"];
+const CONFIG_LANGUAGES: &[&str] = &[
+    "JSON",
+    "YAML",
+    "TOML",
+    "INI",
+    "XML",
+    "Dockerfile",
+    "Makefile",
+    "CMake",
+    "Starlark",
+];
+
+const C_STYLE: CommentSyntax = CommentSyntax::new(&["//"], Some(("/*", "*/")));
+/// C-style comments in languages where `'` delimits strings, not characters
+const C_STYLE_QUOTED: CommentSyntax = C_STYLE.quotes(&['"', '\'']);
+const JS_STYLE: CommentSyntax = C_STYLE.quotes(&['"', '\'', '`']);
+const HASH: CommentSyntax = CommentSyntax::new(&["#"], None).quotes(&['"', '\'']);
+const MARKUP: CommentSyntax = CommentSyntax::new(&[], Some(("<!--", "-->")));
+
+/// Comment markers per language; languages not listed have no comments.
+const COMMENTS: &[(&str, CommentSyntax)] = &[
+    (
+        "Batchfile",
+        CommentSyntax::new(&["REM ", "rem ", "::"], None),
+    ),
+    ("C", C_STYLE),
+    ("C#", C_STYLE),
+    ("C++", C_STYLE),
+    ("CMake", HASH),
+    (
+        "CSS",
+        CommentSyntax::new(&[], Some(("/*", "*/"))).quotes(&['"', '\'']),
+    ),
+    ("Clojure", CommentSyntax::new(&[";"], None)),
+    ("Dart", C_STYLE_QUOTED),
+    ("Dockerfile", HASH),
+    ("Elixir", HASH),
+    ("Emacs Lisp", CommentSyntax::new(&[";"], None)),
+    ("Erlang", CommentSyntax::new(&["%"], None)),
+    ("F#", CommentSyntax::new(&["//"], Some(("(*", "*)")))),
+    ("Go", C_STYLE.quotes(&['"', '`'])),
+    ("Gradle", C_STYLE_QUOTED),
+    ("GraphQL", HASH),
+    ("Groovy", C_STYLE_QUOTED),
+    ("HCL", CommentSyntax::new(&["#", "//"], Some(("/*", "*/")))),
+    ("HTML", MARKUP),
+    ("Haskell", CommentSyntax::new(&["--"], Some(("{-", "-}")))),
+    ("INI", CommentSyntax::new(&[";", "#"], None)),
+    ("Java", C_STYLE),
+    ("JavaScript", JS_STYLE),
+    ("Julia", CommentSyntax::new(&["#"], Some(("#=", "=#")))),
+    ("Kotlin", C_STYLE),
+    ("Less", C_STYLE_QUOTED),
+    (
+        "Lua",
+        CommentSyntax::new(&["--"], Some(("--[[", "]]"))).quotes(&['"', '\'']),
+    ),
+    ("Makefile", HASH),
+    ("Markdown", MARKUP),
+    ("Nim", HASH),
+    ("OCaml", CommentSyntax::new(&[], Some(("(*", "*)")))),
+    ("Objective-C", C_STYLE),
+    ("Objective-C++", C_STYLE),
+    (
+        "PHP",
+        CommentSyntax::new(&["//", "#"], Some(("/*", "*/"))).quotes(&['"', '\'']),
+    ),
+    ("Perl", HASH),
+    (
+        "PowerShell",
+        CommentSyntax::new(&["#"], Some(("<#", "#>"))).quotes(&['"', '\'']),
+    ),
+    ("Protocol Buffer", C_STYLE),
+    ("Python", HASH),
+    ("R", HASH),
+    (
+        "Ruby",
+        CommentSyntax::new(&["#"], Some(("=begin", "=end"))).quotes(&['"', '\'']),
+    ),
+    ("Rust", C_STYLE),
+    ("SCSS", C_STYLE_QUOTED),
+    (
+        "SQL",
+        CommentSyntax::new(&["--"], Some(("/*", "*/"))).quotes(&['"', '\'']),
+    ),
+    ("Sass", C_STYLE_QUOTED),
+    ("Scala", C_STYLE),
+    ("Shell", HASH),
+    ("Solidity", C_STYLE_QUOTED),
+    ("Starlark", HASH),
+    ("Svelte", MARKUP),
+    ("Swift", C_STYLE),
+    ("TOML", HASH),
+    ("TSX", JS_STYLE),
+    ("TeX", CommentSyntax::new(&["%"], None)),
+    ("TypeScript", JS_STYLE),
+    (
+        "Vim Script",
+        CommentSyntax::new(&["\""], None).quotes(&['\'']),
+    ),
+    ("Vue", MARKUP),
+    ("XML", MARKUP),
+    ("YAML", HASH),
+    ("Zig", CommentSyntax::new(&["//"], None)),
+    ("fish", HASH),
+];
+
+#[derive(Clone, Copy)]
+struct CommentSyntax {
+    line: &'static [&'static str],
+    block: Option<(&'static str, &'static str)>,
+    /// Characters that open and close a string literal
+    quotes: &'static [char],
+}
+
+impl CommentSyntax {
+    const fn new(
+        line: &'static [&'static str],
+        block: Option<(&'static str, &'static str)>,
+    ) -> Self {
+        Self {
+            line,
+            block,
+            quotes: &['"'],
+        }
+    }
+
+    const fn quotes(self, quotes: &'static [char]) -> Self {
+        Self { quotes, ..self }
+    }
+}
+
+/// Line counts of one file, in the style of cloc: a line holding both code
+/// and a comment counts as code.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LineCounts {
+    pub lines: i64,
+    pub blank: i64,
+    pub comment: i64,
+    pub code: i64,
+}
+
+/// Count blank, comment and code lines of `text` written in `language`.
+pub fn count_lines(language: Option<&str>, text: &str) -> LineCounts {
+    let syntax = comment_syntax(language);
+
+    let mut counts = LineCounts::default();
+    // Closing marker of the block comment we are inside, if any
+    let mut block_end: Option<&str> = None;
+    for line in text.lines() {
+        counts.lines += 1;
+        let line = line.trim();
+
+        // Blank lines inside a block comment are still blank, as in cloc
+        if line.is_empty() {
+            counts.blank += 1;
+            continue;
+        }
+        let Some(syntax) = syntax else {
+            counts.code += 1;
+            continue;
+        };
+
+        let (has_code, comments) = split_line(line, &syntax, &mut block_end);
+        if has_code {
+            counts.code += 1;
+        } else if !comments.is_empty() {
+            counts.comment += 1;
+        } else {
+            counts.blank += 1;
+        }
+    }
+    counts
+}
+
+/// The comments of `text` written in `language`, without their markers, each
+/// with its 1-based line number. Empty for languages without comments.
+pub fn comments<'a>(language: Option<&str>, text: &'a str) -> Vec<(usize, &'a str)> {
+    let Some(syntax) = comment_syntax(language) else {
+        return Vec::new();
+    };
+
+    let mut found = Vec::new();
+    let mut block_end: Option<&str> = None;
+    for (i, line) in text.lines().enumerate() {
+        let (_, comments) = split_line(line, &syntax, &mut block_end);
+        found.extend(comments.into_iter().map(|comment| (i + 1, comment)));
+    }
+    found
+}
+
+fn comment_syntax(language: Option<&str>) -> Option<CommentSyntax> {
+    let language = language?;
+    COMMENTS
+        .iter()
+        .find(|(name, _)| *name == language)
+        .map(|(_, syntax)| *syntax)
+}
+
+/// Split one line into whether it holds code and the text of its comments,
+/// without the markers. `block_end` carries an unclosed block comment from
+/// one line to the next.
+fn split_line<'a>(
+    line: &'a str,
+    syntax: &CommentSyntax,
+    block_end: &mut Option<&'static str>,
+) -> (bool, Vec<&'a str>) {
+    let mut has_code = false;
+    let mut comments = Vec::new();
+    let mut rest = line;
+    loop {
+        if let Some(end) = *block_end {
+            match rest.find(end) {
+                Some(pos) => {
+                    comments.push(&rest[..pos]);
+                    rest = &rest[pos + end.len()..];
+                    *block_end = None;
+                }
+                None => {
+                    comments.push(rest);
+                    break;
+                }
+            }
+        }
+
+        let Some((pos, marker, end)) = next_comment(rest, syntax) else {
+            has_code |= !rest.trim().is_empty();
+            break;
+        };
+        has_code |= !rest[..pos].trim().is_empty();
+        rest = &rest[pos + marker.len()..];
+        match end {
+            Some(end) => *block_end = Some(end),
+            None => {
+                comments.push(rest);
+                break;
+            }
+        }
+    }
+    (has_code, comments)
+}
+
+/// The first comment marker in `code` outside a string or character
+/// literal: its offset, the marker, and the closing marker when it opens a
+/// block comment.
+fn next_comment(
+    code: &str,
+    syntax: &CommentSyntax,
+) -> Option<(usize, &'static str, Option<&'static str>)> {
+    // Closing quote of the string we are inside, if any
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+    let mut skip_to = 0;
+    for (pos, c) in code.char_indices() {
+        if pos < skip_to {
+            continue;
+        }
+        if let Some(closing) = quote {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == closing {
+                quote = None;
+            }
+            continue;
+        }
+
+        let rest = &code[pos..];
+        // Block markers first: Lua's "--[[" also starts with "--"
+        if let Some((start, end)) = syntax.block.filter(|(start, _)| rest.starts_with(start)) {
+            return Some((pos, start, Some(end)));
+        }
+        if let Some(marker) = syntax.line.iter().find(|marker| rest.starts_with(*marker)) {
+            return Some((pos, marker, None));
+        }
+        if syntax.quotes.contains(&c) {
+            quote = Some(c);
+        } else if c == '\'' {
+            // A character literal such as '"' must not open a string, while
+            // other quotes (Rust lifetimes, Haskell primes) are plain code
+            skip_to = pos + char_literal_len(rest).unwrap_or(0);
+        }
+    }
+    None
+}
+
+/// Length of the character literal at the start of `code`: `'x'`, or an
+/// escape such as `'\''` or `'\u{1F600}'`.
+fn char_literal_len(code: &str) -> Option<usize> {
+    let mut chars = code.char_indices().skip(1);
+    let (_, first) = chars.next()?;
+    let closing = if first == '\\' {
+        chars.next()?;
+        chars.take(10).find(|(_, c)| *c == '\'')
+    } else {
+        chars.next().filter(|(_, c)| *c == '\'')
+    };
+    closing.map(|(pos, c)| pos + c.len_utf8())
+}
+
+/// Language and category of a file.
+#[derive(Debug, Clone)]
+pub struct Classification {
+    pub language: Option<String>,
+    /// source, test, docs, config, generated, vendored, or other
+    pub category: &'static str,
+}
+
+/// Classifies paths like GitHub linguist: built-in rules by name, extension
+/// and shebang, overridden by the `linguist-language`, `linguist-vendored`,
+/// `linguist-generated` and `linguist-documentation` attributes.
+pub struct Classifier<'r> {
+    repo: &'r Repository,
+    /// Attributes of a fixed revision; the work tree and index when `None`
+    attributes: Option<TreeAttributes>,
+    /// Results per path, so attributes are looked up once
+    cache: HashMap<String, Classification>,
+}
+
+impl<'r> Classifier<'r> {
+    /// Classify with the attributes git would apply today, from the work tree
+    /// and index.
+    pub fn new(repo: &'r Repository) -> Self {
+        Self {
+            repo,
+            attributes: None,
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Classify files of `tree` with the `.gitattributes` that tree contains.
+    pub fn for_tree(repo: &'r Repository, tree: &Tree) -> Result<Self, git2::Error> {
+        Ok(Self::with_attributes(
+            repo,
+            TreeAttributes::load(repo, tree)?,
+        ))
+    }
+
+    /// Classify with attributes already loaded for some revision.
+    pub fn with_attributes(repo: &'r Repository, attributes: TreeAttributes) -> Self {
+        Self {
+            repo,
+            attributes: Some(attributes),
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Classify `path`. `content` is only read when name and extension do
+    /// not identify the language, to look for a shebang.
+    pub fn classify(
+        &mut self,
+        path: &str,
+        content: impl FnOnce() -> Option<Vec<u8>>,
+    ) -> Classification {
+        if let Some(known) = self.cache.get(path) {
+            return known.clone();
+        }
+
+        let language = match self.attr(path, "linguist-language") {
+            Some(AttrOverride::Value(language)) => Some(language),
+            _ => detect_language(path, content),
+        };
+
+        let flag = |this: &Self, name: &str, default: bool| match this.attr(path, name) {
+            Some(AttrOverride::Set) => true,
+            Some(AttrOverride::Unset) => false,
+            _ => default,
+        };
+        let category = if flag(self, "linguist-vendored", is_vendored(path)) {
+            "vendored"
+        } else if flag(self, "linguist-generated", is_generated(path)) {
+            "generated"
+        } else if is_test(path) {
+            "test"
+        } else if flag(
+            self,
+            "linguist-documentation",
+            is_docs(path, language.as_deref()),
+        ) {
+            "docs"
+        } else if language
+            .as_deref()
+            .is_some_and(|l| CONFIG_LANGUAGES.contains(&l))
+            || is_dotfile(path)
+        {
+            "config"
+        } else if language.is_some() {
+            "source"
+        } else {
+            "other"
+        };
+
+        let classification = Classification { language, category };
+        self.cache.insert(path.to_string(), classification.clone());
+        classification
+    }
+
+    fn attr(&self, path: &str, name: &str) -> Option<AttrOverride> {
+        if let Some(attributes) = &self.attributes {
+            return match attributes.get(path, name)? {
+                "set" | "true" => Some(AttrOverride::Set),
+                "unset" | "false" => Some(AttrOverride::Unset),
+                value => Some(AttrOverride::Value(value.to_string())),
+            };
+        }
+
+        let value = self
+            .repo
+            .get_attr(Path::new(path), name, AttrCheckFlags::default())
+            .ok()?;
+        match AttrValue::from_string(value) {
+            AttrValue::True => Some(AttrOverride::Set),
+            AttrValue::False => Some(AttrOverride::Unset),
+            AttrValue::String(s) => Some(match s {
+                "true" => AttrOverride::Set,
+                "false" => AttrOverride::Unset,
+                _ => AttrOverride::Value(s.to_string()),
+            }),
+            AttrValue::Bytes(b) => {
+                Some(AttrOverride::Value(String::from_utf8_lossy(b).to_string()))
+            }
+            AttrValue::Unspecified => None,
+        }
+    }
+}
+
+enum AttrOverride {
+    Set,
+    Unset,
+    Value(String),
+}
+
+/// Language from the file name, then the extension, then a `#!` line.
+pub fn detect_language(path: &str, content: impl FnOnce() -> Option<Vec<u8>>) -> Option<String> {
+    let name = file_name(path);
+    if let Some((_, language)) = FILENAMES.iter().find(|(n, _)| *n == name) {
+        return Some(language.to_string());
+    }
+    if let Some((_, ext)) = name.rsplit_once('.').filter(|(stem, _)| !stem.is_empty()) {
+        let ext = ext.to_ascii_lowercase();
+        if let Some((_, language)) = EXTENSIONS.iter().find(|(e, _)| *e == ext) {
+            return Some(language.to_string());
+        }
+        return None;
+    }
+    content().and_then(|bytes| shebang_language(&bytes))
+}
+
+/// `#!/usr/bin/env python3` and `#!/bin/sh -e` style interpreter lines.
+fn shebang_language(content: &[u8]) -> Option<String> {
+    let first_line = content.split(|&b| b == b'\n').next()?;
+    let line = std::str::from_utf8(first_line).ok()?.strip_prefix("#!")?;
+    let mut words = line.split_whitespace();
+    let mut interpreter = words.next()?.rsplit('/').next()?;
+    if interpreter == "env" {
+        interpreter = words.find(|w| !w.starts_with('-') && !w.contains('='))?;
+    }
+    // python3.11 -> python
+    let interpreter = interpreter.trim_end_matches(|c: char| c.is_ascii_digit() || c == '.');
+    INTERPRETERS
+        .iter()
+        .find(|(i, _)| *i == interpreter)
+        .map(|(_, language)| language.to_string())
+}
+
+fn file_name(path: &str) -> &str {
+    path.rsplit('/').next().unwrap_or(path)
+}
+
+fn directories(path: &str) -> impl Iterator<Item = &str> {
+    let mut parts: Vec<&str> = path.split('/').collect();
+    parts.pop();
+    parts.into_iter()
+}
+
+fn is_vendored(path: &str) -> bool {
+    directories(path).any(|dir| VENDORED_DIRS.contains(&dir))
+}
+
+fn is_generated(path: &str) -> bool {
+    let name = file_name(path);
+    GENERATED_FILES.contains(&name) || GENERATED_SUFFIXES.iter().any(|s| name.ends_with(s))
+}
+
+fn is_test(path: &str) -> bool {
+    if directories(path).any(|dir| TEST_DIRS.contains(&dir)) {
+        return true;
+    }
+    let name = file_name(path);
+    let stem = name.split('.').next().unwrap_or(name);
+    stem.starts_with("test_")
+        || stem.ends_with("_test")
+        || stem.ends_with("_spec")
+        || (stem.ends_with("Test") && name.ends_with(".java"))
+        || name.contains(".test.")
+        || name.contains(".spec.")
+}
+
+fn is_docs(path: &str, language: Option<&str>) -> bool {
+    let name = file_name(path);
+    let stem = name.split('.').next().unwrap_or(name);
+    directories(path).any(|dir| DOCS_DIRS.contains(&dir))
+        || DOCS_FILES.contains(&stem.to_ascii_uppercase().as_str())
+        || language.is_some_and(|l| DOCS_LANGUAGES.contains(&l))
+}
+
+fn is_dotfile(path: &str) -> bool {
+    file_name(path).starts_with('.')
+}
@@ -0,0 +1,90 @@
+use git2::{ObjectType, Odb, Oid};
+use std::path::{Path, PathBuf};
+
+/// Pointer files are small; anything larger can't be one.
+const MAX_POINTER_SIZE: usize = 1024;
+
+const POINTER_VERSION: &str = "version https://git-lfs.github.com/spec/v1";
+
+pub struct LfsPointer {
+    /// SHA-256 of the real content
+    pub oid: String,
+    pub size: u64,
+}
+
+/// Parse the body of a Git LFS pointer file.
+pub fn parse_pointer(content: &[u8]) -> Option<LfsPointer> {
+    if content.len() > MAX_POINTER_SIZE {
+        return None;
+    }
+    let text = std::str::from_utf8(content).ok()?;
+    let mut lines = text.lines();
+    if lines.next()? != POINTER_VERSION {
+        return None;
+    }
+
+    let mut oid = None;
+    let mut size = None;
+    for line in lines {
+        if let Some(value) = line.strip_prefix("oid sha256:") {
+            if value.len() == 64 && value.bytes().all(|b| b.is_ascii_hexdigit()) {
+                oid = Some(value.to_string());
+            }
+        } else if let Some(value) = line.strip_prefix("size ") {
+            size = value.parse().ok();
+        }
+    }
+
+    Some(LfsPointer {
+        oid: oid?,
+        size: size?,
+    })
+}
+
+/// Read a blob as an LFS pointer, checking its size before loading it.
+pub fn read_pointer(odb: &Odb, id: Oid) -> Option<LfsPointer> {
+    inspect_blob(odb, id)?.pointer
+}
+
+/// A blob's stored size and, when it is an LFS pointer, the parsed pointer.
+pub struct BlobInfo {
+    pub size: u64,
+    pub pointer: Option<LfsPointer>,
+}
+
+impl BlobInfo {
+    /// Size of the real content, looking through LFS pointers
+    pub fn resolved_size(&self) -> u64 {
+        self.pointer.as_ref().map_or(self.size, |p| p.size)
+    }
+}
+
+/// Inspect a blob with a single header read, plus one object read when it is
+/// small enough to be a pointer. Callers handling many blobs should share `odb`.
+pub fn inspect_blob(odb: &Odb, id: Oid) -> Option<BlobInfo> {
+    let (size, kind) = odb.read_header(id).ok()?;
+    if kind != ObjectType::Blob {
+        return None;
+    }
+    let pointer = if size <= MAX_POINTER_SIZE {
+        odb.read(id)
+            .ok()
+            .and_then(|object| parse_pointer(object.data()))
+    } else {
+        None
+    };
+    Some(BlobInfo {
+        size: size as u64,
+        pointer,
+    })
+}
+
+/// Where `git lfs` keeps the content for `oid` in the local cache.
+pub fn lfs_object_path(common_dir: &Path, oid: &str) -> PathBuf {
+    common_dir
+        .join("lfs")
+        .join("objects")
+        .join(&oid[..2])
+        .join(&oid[2..4])
+        .join(oid)
+}
@@ -0,0 +1,52 @@
+use crate::error::Result;
+use git2::{Oid, Repository};
+use std::collections::HashMap;
+
+/// The first-parent history of HEAD and, for everything else, the mainline
+/// merge that brought it in.
+#[derive(Debug, Default)]
+pub struct Mainline {
+    /// First-parent steps from HEAD for each mainline commit (HEAD is 0)
+    pub depth: HashMap<Oid, i64>,
+    /// Mainline merge whose side history first contains the commit
+    pub merged_in_by: HashMap<Oid, Oid>,
+}
+
+impl Mainline {
+    pub fn of_head(repo: &Repository) -> Result<Self> {
+        let mut mainline = Self::default();
+        let Some(head) = repo.head().ok().and_then(|h| h.target()) else {
+            return Ok(mainline);
+        };
+
+        let mut chain: Vec<Oid> = Vec::new();
+        let mut next = Some(head);
+        while let Some(oid) = next {
+            if mainline.depth.contains_key(&oid) {
+                break;
+            }
+            mainline.depth.insert(oid, chain.len() as i64);
+            chain.push(oid);
+            next = repo.find_commit(oid).ok().and_then(|c| c.parent_id(0).ok());
+        }
+
+        // Oldest merge first: a commit reachable from an earlier merge landed there
+        for merge in chain.iter().rev() {
+            let Ok(commit) = repo.find_commit(*merge) else {
+                continue;
+            };
+            let mut stack: Vec<Oid> = commit.parent_ids().skip(1).collect();
+            while let Some(oid) = stack.pop() {
+                if mainline.depth.contains_key(&oid) || mainline.merged_in_by.contains_key(&oid) {
+                    continue;
+                }
+                mainline.merged_in_by.insert(oid, *merge);
+                if let Ok(side) = repo.find_commit(oid) {
+                    stack.extend(side.parent_ids());
+                }
+            }
+        }
+
+        Ok(mainline)
+    }
+}
@@ -1,3 +1,16 @@
+pub mod attributes;
+pub mod blame;
+pub mod codeowners;
+pub mod commit_graph;
+pub mod diff;
+pub mod ignore;
+pub mod language;
+pub mod lfs;
+pub mod mainline;
+pub mod pack;
+pub mod patch_id;
+pub mod pattern;
+pub mod reachability;
 mod repository;
 
-pub use repository::GitRepo;
+pub use repository::{common_dir, global_config_file, merged_from_side, GitRepo};
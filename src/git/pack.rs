@@ -0,0 +1,190 @@
+use crate::error::Result;
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// Extensions that may legitimately sit next to a `.pack` file.
+const PACK_EXTENSIONS: &[&str] = &["pack", "idx", "bitmap", "rev", "keep", "promisor", "mtimes"];
+
+pub struct PackFile {
+    pub name: String,
+    pub path: PathBuf,
+    pub size: u64,
+    pub object_count: Option<u32>,
+    pub has_idx: bool,
+    pub has_bitmap: bool,
+    pub has_rev: bool,
+    pub has_keep: bool,
+    pub modified: Option<SystemTime>,
+}
+
+impl PackFile {
+    pub fn idx_path(&self) -> PathBuf {
+        self.path.with_extension("idx")
+    }
+}
+
+pub struct GarbageFile {
+    pub path: PathBuf,
+    pub size: u64,
+}
+
+/// List the packfiles under `objects/pack`, along with any stray files that
+/// git would report as garbage in `count-objects -v`.
+pub fn scan_packs(objects_dir: &Path) -> Result<(Vec<PackFile>, Vec<GarbageFile>)> {
+    let pack_dir = objects_dir.join("pack");
+    let mut packs = Vec::new();
+    let mut garbage = Vec::new();
+
+    if !pack_dir.is_dir() {
+        return Ok((packs, garbage));
+    }
+
+    for entry in fs::read_dir(&pack_dir)?.flatten() {
+        let path = entry.path();
+        if !path.is_file() {
+            continue;
+        }
+        let metadata = entry.metadata()?;
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+
+        let is_known = file_name.starts_with("pack-") && PACK_EXTENSIONS.contains(&extension);
+        if !is_known {
+            garbage.push(GarbageFile {
+                path,
+                size: metadata.len(),
+            });
+            continue;
+        }
+
+        // Every companion file needs its pack, and every pack needs its index
+        if extension != "pack" {
+            if !path.with_extension("pack").exists() {
+                garbage.push(GarbageFile {
+                    path,
+                    size: metadata.len(),
+                });
+            }
+            continue;
+        }
+
+        let has_idx = path.with_extension("idx").exists();
+        if !has_idx {
+            garbage.push(GarbageFile {
+                path: path.clone(),
+                size: metadata.len(),
+            });
+        }
+
+        packs.push(PackFile {
+            name: file_name,
+            object_count: read_pack_object_count(&path),
+            has_idx,
+            has_bitmap: path.with_extension("bitmap").exists(),
+            has_rev: path.with_extension("rev").exists(),
+            has_keep: path.with_extension("keep").exists(),
+            size: metadata.len(),
+            modified: metadata.modified().ok(),
+            path,
+        });
+    }
+
+    packs.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok((packs, garbage))
+}
+
+/// Read the object count from the 12-byte pack header ("PACK", version, count).
+fn read_pack_object_count(path: &Path) -> Option<u32> {
+    let mut header = [0u8; 12];
+    fs::File::open(path).ok()?.read_exact(&mut header).ok()?;
+    if &header[..4] != b"PACK" {
+        return None;
+    }
+    Some(u32::from_be_bytes([header[8], header[9], header[10], header[11]]))
+}
+
+/// Read the sorted list of object ids stored in a pack index (v1 or v2).
+pub fn read_pack_index_ids(idx_path: &Path) -> Result<Vec<[u8; 20]>> {
+    let data = fs::read(idx_path)?;
+    let mut ids = Vec::new();
+
+    let (fanout_start, is_v2) = if data.len() >= 8 && data[..4] == [0xff, b't', b'O', b'c'] {
+        (8, true)
+    } else {
+        (0, false)
+    };
+
+    let fanout_end = fanout_start + 256 * 4;
+    if data.len() < fanout_end {
+        return Ok(ids);
+    }
+    let count = u32::from_be_bytes([
+        data[fanout_end - 4],
+        data[fanout_end - 3],
+        data[fanout_end - 2],
+        data[fanout_end - 1],
+    ]) as usize;
+
+    // v2 stores the ids contiguously; v1 interleaves a 4-byte offset before each id
+    let stride = if is_v2 { 20 } else { 24 };
+    let skip = if is_v2 { 0 } else { 4 };
+
+    for i in 0..count {
+        let start = fanout_end + i * stride + skip;
+        if let Some(bytes) = data.get(start..start + 20) {
+            let mut id = [0u8; 20];
+            id.copy_from_slice(bytes);
+            ids.push(id);
+        }
+    }
+
+    Ok(ids)
+}
+
+pub struct LooseObject {
+    pub id: String,
+    pub size: u64,
+}
+
+/// Walk the two-character fan-out directories holding loose objects. Files
+/// whose names aren't object ids are returned as garbage.
+pub fn scan_loose_objects(objects_dir: &Path) -> Result<(Vec<LooseObject>, Vec<GarbageFile>)> {
+    let mut objects = Vec::new();
+    let mut garbage = Vec::new();
+
+    if !objects_dir.is_dir() {
+        return Ok((objects, garbage));
+    }
+
+    for dir_entry in fs::read_dir(objects_dir)?.flatten() {
+        let prefix = dir_entry.file_name().to_string_lossy().to_string();
+        if prefix.len() != 2 || !is_hex(&prefix) || !dir_entry.path().is_dir() {
+            continue;
+        }
+
+        for entry in fs::read_dir(dir_entry.path())?.flatten() {
+            let rest = entry.file_name().to_string_lossy().to_string();
+            let size = entry.metadata().map(|m| m.len()).unwrap_or(0);
+            if rest.len() == 38 && is_hex(&rest) {
+                objects.push(LooseObject {
+                    id: format!("{}{}", prefix, rest),
+                    size,
+                });
+            } else {
+                garbage.push(GarbageFile {
+                    path: entry.path(),
+                    size,
+                });
+            }
+        }
+    }
+
+    Ok((objects, garbage))
+}
+
+fn is_hex(s: &str) -> bool {
+    s.bytes().all(|b| b.is_ascii_hexdigit())
+}
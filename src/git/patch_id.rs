@@ -0,0 +1,146 @@
+use crate::error::Result;
+use crate::git::diff::{diff_options, parent_diffs};
+use git2::{Commit, Delta, Diff, Oid, Patch, Repository};
+use sha1::{Digest, Sha1};
+
+/// Stable patch-id of a commit's change against its parent, as printed by
+/// `git patch-id --stable`. Merges and empty commits have none.
+pub fn commit_patch_id(repo: &Repository, commit: &Commit) -> Result<Option<Oid>> {
+    if commit.parent_count() > 1 {
+        return Ok(None);
+    }
+    match parent_diffs(repo, commit, true)?.first() {
+        Some((_, diff)) => diff_patch_id(diff),
+        None => Ok(None),
+    }
+}
+
+/// Patch-id of the change that would undo `commit`. A commit that reverts
+/// `commit` has this as its own patch-id.
+pub fn inverse_patch_id(repo: &Repository, commit: &Commit) -> Result<Option<Oid>> {
+    if commit.parent_count() > 1 {
+        return Ok(None);
+    }
+    let tree = commit.tree()?;
+    let parent_tree = match commit.parent(0) {
+        Ok(parent) => Some(parent.tree()?),
+        Err(_) => None,
+    };
+    let diff =
+        repo.diff_tree_to_tree(Some(&tree), parent_tree.as_ref(), Some(&mut diff_options()))?;
+    diff_patch_id(&diff)
+}
+
+/// Stable patch-id of an arbitrary diff. Each file is hashed on its own with
+/// whitespace and line numbers dropped, and the per-file hashes are summed,
+/// so the result does not depend on file order or hunk offsets.
+pub fn diff_patch_id(diff: &Diff) -> Result<Option<Oid>> {
+    let mut total = [0u8; 20];
+    let mut any = false;
+
+    for index in 0..diff.deltas().len() {
+        let Some(patch) = Patch::from_diff(diff, index)? else {
+            continue;
+        };
+        let delta = patch.delta();
+        let old_path = delta.old_file().path_bytes().unwrap_or_default().to_vec();
+        let new_path = delta.new_file().path_bytes().unwrap_or_default().to_vec();
+        let old_mode = u32::from(delta.old_file().mode());
+        let new_mode = u32::from(delta.new_file().mode());
+        let status = delta.status();
+        let is_binary = delta.flags().is_binary();
+        let old_id = delta.old_file().id();
+        let new_id = delta.new_file().id();
+
+        let mut hasher = Sha1::new();
+        hasher.update(b"diff--gita/");
+        hasher.update(strip_space(&old_path));
+        hasher.update(b"b/");
+        hasher.update(strip_space(&new_path));
+
+        match status {
+            Delta::Added => {
+                hasher.update(b"newfilemode");
+                hasher.update(format!("{:06o}", new_mode));
+            }
+            Delta::Deleted => {
+                hasher.update(b"deletedfilemode");
+                hasher.update(format!("{:06o}", old_mode));
+            }
+            _ if old_mode != new_mode => {
+                hasher.update(b"oldmode");
+                hasher.update(format!("{:06o}", old_mode));
+                hasher.update(b"newmode");
+                hasher.update(format!("{:06o}", new_mode));
+            }
+            _ => {}
+        }
+
+        if is_binary {
+            hasher.update(old_id.to_string());
+            hasher.update(new_id.to_string());
+        } else if patch.num_hunks() > 0 {
+            // Like git, mode changes and empty files have no ---/+++ lines
+            match status {
+                Delta::Added => {
+                    hasher.update(b"---/dev/null+++b/");
+                    hasher.update(strip_space(&new_path));
+                }
+                Delta::Deleted => {
+                    hasher.update(b"---a/");
+                    hasher.update(strip_space(&old_path));
+                    hasher.update(b"+++/dev/null");
+                }
+                _ => {
+                    hasher.update(b"---a/");
+                    hasher.update(strip_space(&old_path));
+                    hasher.update(b"+++b/");
+                    hasher.update(strip_space(&new_path));
+                }
+            }
+
+            // Hunk headers carry line numbers and are left out
+            for hunk in 0..patch.num_hunks() {
+                for line in 0..patch.num_lines_in_hunk(hunk)? {
+                    let line = patch.line_in_hunk(hunk, line)?;
+                    match line.origin() {
+                        // Context lines lose their leading space with the rest
+                        ' ' => hasher.update(strip_space(line.content())),
+                        '+' | '-' => {
+                            hasher.update([line.origin() as u8]);
+                            hasher.update(strip_space(line.content()));
+                        }
+                        // git skips "\ No newline at end of file" markers too
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        add_with_carry(&mut total, &hasher.finalize());
+        any = true;
+    }
+
+    if !any {
+        return Ok(None);
+    }
+    Ok(Some(Oid::from_bytes(&total)?))
+}
+
+fn strip_space(bytes: &[u8]) -> Vec<u8> {
+    bytes
+        .iter()
+        .copied()
+        .filter(|b| !b.is_ascii_whitespace() && *b != 0x0b)
+        .collect()
+}
+
+/// Little-endian byte-wise sum, matching how git combines per-file hashes.
+fn add_with_carry(total: &mut [u8; 20], hash: &[u8]) {
+    let mut carry: u16 = 0;
+    for (byte, add) in total.iter_mut().zip(hash) {
+        carry += *byte as u16 + *add as u16;
+        *byte = carry as u8;
+        carry >>= 8;
+    }
+}
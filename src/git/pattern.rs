@@ -0,0 +1,146 @@
+/// A gitignore-style path pattern, as used by `.gitignore` and `CODEOWNERS`.
+#[derive(Debug, Clone)]
+pub struct PathPattern {
+    pub negated: bool,
+    pub dir_only: bool,
+    /// Patterns containing a slash match from their base directory; others
+    /// match the final path component at any depth
+    pub anchored: bool,
+    glob: String,
+}
+
+impl PathPattern {
+    /// Parse one pattern line. Returns `None` for blanks and comments.
+    pub fn parse(line: &str) -> Option<Self> {
+        let mut text = line.trim_end_matches(['\n', '\r']);
+
+        // Trailing spaces are ignored unless escaped
+        while text.ends_with(' ') && !text.ends_with("\\ ") {
+            text = &text[..text.len() - 1];
+        }
+        if text.is_empty() || text.starts_with('#') {
+            return None;
+        }
+
+        let mut negated = false;
+        if let Some(rest) = text.strip_prefix('!') {
+            negated = true;
+            text = rest;
+        } else if text.starts_with("\\!") || text.starts_with("\\#") {
+            text = &text[1..];
+        }
+
+        let mut dir_only = false;
+        if let Some(rest) = text.strip_suffix('/') {
+            dir_only = true;
+            text = rest;
+        }
+
+        let anchored = text.contains('/');
+        let glob = text.strip_prefix('/').unwrap_or(text).to_string();
+        if glob.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            negated,
+            dir_only,
+            anchored,
+            glob,
+        })
+    }
+
+    /// Match a slash-separated path relative to the pattern's base directory.
+    pub fn matches(&self, path: &str, is_dir: bool) -> bool {
+        if self.dir_only && !is_dir {
+            return false;
+        }
+        if self.anchored {
+            wildmatch(self.glob.as_bytes(), path.as_bytes())
+        } else {
+            let name = path.rsplit('/').next().unwrap_or(path);
+            wildmatch(self.glob.as_bytes(), name.as_bytes())
+        }
+    }
+}
+
+/// Glob match with `WM_PATHNAME` semantics: `*` and `?` stop at slashes,
+/// `**` between slashes spans directories.
+pub fn wildmatch(pattern: &[u8], text: &[u8]) -> bool {
+    match pattern.first() {
+        None => text.is_empty(),
+        Some(b'*') if pattern.get(1) == Some(&b'*') => {
+            let rest = &pattern[2..];
+            // "**/" also matches zero directories
+            if let Some(after) = rest.strip_prefix(b"/") {
+                if wildmatch(after, text) {
+                    return true;
+                }
+            }
+            (0..=text.len()).any(|i| wildmatch(rest, &text[i..]))
+        }
+        Some(b'*') => {
+            let rest = &pattern[1..];
+            for i in 0..=text.len() {
+                if wildmatch(rest, &text[i..]) {
+                    return true;
+                }
+                if text.get(i) == Some(&b'/') {
+                    break;
+                }
+            }
+            false
+        }
+        Some(b'?') => match text.first() {
+            Some(&c) if c != b'/' => wildmatch(&pattern[1..], &text[1..]),
+            _ => false,
+        },
+        Some(b'[') => match (text.first(), match_class(&pattern[1..])) {
+            (Some(&c), Some((matcher, len))) if c != b'/' && matcher(c) => {
+                wildmatch(&pattern[1 + len..], &text[1..])
+            }
+            (_, Some(_)) => false,
+            // An unterminated class is a literal '['
+            (_, None) => text.first() == Some(&b'[') && wildmatch(&pattern[1..], &text[1..]),
+        },
+        Some(b'\\') if pattern.len() > 1 => {
+            text.first() == Some(&pattern[1]) && wildmatch(&pattern[2..], &text[1..])
+        }
+        Some(&p) => text.first() == Some(&p) && wildmatch(&pattern[1..], &text[1..]),
+    }
+}
+
+/// Parse a bracket expression body (after '['), returning a byte matcher and
+/// the number of pattern bytes consumed including the closing ']'.
+fn match_class(class: &[u8]) -> Option<(impl Fn(u8) -> bool, usize)> {
+    let mut i = 0;
+    let negated = matches!(class.first(), Some(b'!') | Some(b'^'));
+    if negated {
+        i += 1;
+    }
+
+    let mut ranges: Vec<(u8, u8)> = Vec::new();
+    let mut first = true;
+    while i < class.len() {
+        let c = class[i];
+        if c == b']' && !first {
+            let matcher = move |b: u8| ranges.iter().any(|&(lo, hi)| lo <= b && b <= hi) != negated;
+            return Some((matcher, i + 1));
+        }
+        first = false;
+        let lo = if c == b'\\' && i + 1 < class.len() {
+            i += 1;
+            class[i]
+        } else {
+            c
+        };
+        if class.get(i + 1) == Some(&b'-') && class.get(i + 2).is_some_and(|&h| h != b']') {
+            ranges.push((lo, class[i + 2]));
+            i += 3;
+        } else {
+            ranges.push((lo, lo));
+            i += 1;
+        }
+    }
+    None
+}
@@ -0,0 +1,208 @@
+use crate::error::Result;
+use crate::git::GitRepo;
+use git2::{ObjectType, Oid, Repository};
+use std::collections::HashMap;
+use std::fs;
+
+/// Every reference and HEAD, paired with the object id it points at.
+pub fn ref_roots(repo: &GitRepo) -> Result<Vec<(String, Oid)>> {
+    let git_repo = repo.inner();
+    let mut roots = Vec::new();
+
+    for reference in git_repo.references()?.flatten() {
+        let target = match reference.target() {
+            Some(oid) => Some(oid),
+            None => reference.resolve().ok().and_then(|r| r.target()),
+        };
+        if let (Some(name), Some(oid)) = (reference.name(), target) {
+            roots.push((name.to_string(), oid));
+        }
+    }
+
+    // HEAD comes last so named refs take precedence; it still covers a detached HEAD
+    if let Some(oid) = git_repo.head().ok().and_then(|h| h.target()) {
+        roots.push(("HEAD".to_string(), oid));
+    }
+
+    Ok(roots)
+}
+
+/// Every non-zero id recorded in any reflog, labelled like `refs/heads/main@{3}`.
+pub fn reflog_roots(repo: &GitRepo) -> Result<Vec<(String, Oid)>> {
+    let git_repo = repo.inner();
+    let mut roots = Vec::new();
+
+    for name in reflog_names(repo) {
+        if let Ok(reflog) = git_repo.reflog(&name) {
+            for (index, entry) in reflog.iter().enumerate() {
+                let label = format!("{}@{{{}}}", name, index);
+                for oid in [entry.id_new(), entry.id_old()] {
+                    if !oid.is_zero() {
+                        roots.push((label.clone(), oid));
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(roots)
+}
+
+/// Names of all refs with a reflog, found by walking the `logs` directory.
+pub fn reflog_names(repo: &GitRepo) -> Vec<String> {
+    let logs_dir = repo.common_dir().join("logs");
+    let mut names = Vec::new();
+    if logs_dir.join("HEAD").is_file() {
+        names.push("HEAD".to_string());
+    }
+
+    let mut pending = vec![logs_dir.join("refs")];
+    while let Some(dir) = pending.pop() {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                pending.push(path);
+            } else if let Ok(relative) = path.strip_prefix(&logs_dir) {
+                let name = relative
+                    .components()
+                    .map(|c| c.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/");
+                names.push(name);
+            }
+        }
+    }
+
+    names.sort();
+    names
+}
+
+/// Mark everything reachable from `roots`, recording for each object the
+/// label of the first root that reached it. With `commits_only` the walk
+/// follows parent links and tag targets but never descends into trees.
+pub fn mark_reachable(
+    repo: &Repository,
+    roots: &[(String, Oid)],
+    commits_only: bool,
+) -> HashMap<Oid, String> {
+    let mut reached: HashMap<Oid, String> = HashMap::new();
+
+    for (label, root) in roots {
+        let mut stack = vec![*root];
+        while let Some(oid) = stack.pop() {
+            if reached.contains_key(&oid) {
+                continue;
+            }
+            let Ok(object) = repo.find_object(oid, None) else {
+                continue;
+            };
+            reached.insert(oid, label.clone());
+
+            match object.kind() {
+                Some(ObjectType::Commit) => {
+                    if let Some(commit) = object.as_commit() {
+                        stack.extend(commit.parent_ids());
+                        if !commits_only {
+                            stack.push(commit.tree_id());
+                        }
+                    }
+                }
+                Some(ObjectType::Tag) => {
+                    if let Some(tag) = object.as_tag() {
+                        stack.push(tag.target_id());
+                    }
+                }
+                Some(ObjectType::Tree) => {
+                    if let Some(tree) = object.as_tree() {
+                        for entry in tree.iter() {
+                            match entry.kind() {
+                                Some(ObjectType::Tree) => stack.push(entry.id()),
+                                Some(ObjectType::Blob) => {
+                                    reached.entry(entry.id()).or_insert_with(|| label.clone());
+                                }
+                                // Gitlinks point into another repository
+                                _ => {}
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    reached
+}
+
+/// For every commit reachable from `tips`, the set of tip indexes whose
+/// history contains it. Computed in one topological pass: each commit's set
+/// is pushed down to its parents before they are visited.
+pub fn containment(repo: &Repository, tips: &[Oid]) -> Result<HashMap<Oid, TipSet>> {
+    let mut sets: HashMap<Oid, TipSet> = HashMap::new();
+    if tips.is_empty() {
+        return Ok(sets);
+    }
+
+    let mut revwalk = repo.revwalk()?;
+    revwalk.set_sorting(git2::Sort::TOPOLOGICAL)?;
+    for (index, tip) in tips.iter().enumerate() {
+        if revwalk.push(*tip).is_ok() {
+            sets.entry(*tip)
+                .or_insert_with(|| TipSet::new(tips.len()))
+                .insert(index);
+        }
+    }
+
+    for oid in revwalk {
+        let oid = oid?;
+        let Ok(commit) = repo.find_commit(oid) else {
+            continue;
+        };
+        let set = sets
+            .entry(oid)
+            .or_insert_with(|| TipSet::new(tips.len()))
+            .clone();
+        for parent in commit.parent_ids() {
+            sets.entry(parent)
+                .or_insert_with(|| TipSet::new(tips.len()))
+                .union_with(&set);
+        }
+    }
+
+    Ok(sets)
+}
+
+/// A fixed-size bitset of tip indexes.
+#[derive(Debug, Clone)]
+pub struct TipSet {
+    words: Vec<u64>,
+}
+
+impl TipSet {
+    pub fn new(len: usize) -> Self {
+        Self {
+            words: vec![0; len.div_ceil(64)],
+        }
+    }
+
+    pub fn insert(&mut self, index: usize) {
+        self.words[index / 64] |= 1 << (index % 64);
+    }
+
+    pub fn contains(&self, index: usize) -> bool {
+        self.words[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    pub fn union_with(&mut self, other: &TipSet) {
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word |= other;
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.words.len() * 64).filter(|&i| self.contains(i))
+    }
+}
@@ -1,10 +1,13 @@
 use crate::error::{Result, VcsqlError};
-use git2::{BranchType, Commit, Reference, Repository};
-use std::path::Path;
+use crate::git::commit_graph::CommitGraph;
+use git2::{BranchType, Commit, Oid, Reference, Repository, Revwalk};
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
 
 pub struct GitRepo {
     repo: Repository,
     path: String,
+    first_parent: bool,
 }
 
 impl GitRepo {
@@ -18,15 +21,35 @@ impl GitRepo {
             }
         })?;
 
+        Ok(Self::from_repository(repo))
+    }
+
+    pub fn from_repository(repo: Repository) -> Self {
         let workdir = repo
             .workdir()
             .map(|p| p.display().to_string())
             .unwrap_or_else(|| repo.path().display().to_string());
 
-        Ok(Self {
+        Self {
             repo,
             path: workdir,
-        })
+            first_parent: false,
+        }
+    }
+
+    /// Open every initialized submodule, descending into nested submodules.
+    /// Uninitialized ones are skipped since they have no repository to read.
+    pub fn open_submodules(&self) -> Result<Vec<GitRepo>> {
+        let mut opened = Vec::new();
+        for submodule in self.repo.submodules()? {
+            if let Ok(repo) = submodule.open() {
+                let child = GitRepo::from_repository(repo);
+                let nested = child.open_submodules()?;
+                opened.push(child);
+                opened.extend(nested);
+            }
+        }
+        Ok(opened)
     }
 
     pub fn path(&self) -> &str {
@@ -41,6 +64,34 @@ impl GitRepo {
         &mut self.repo
     }
 
+    /// Restrict commit walks to first parents, skipping history brought in by merges.
+    pub fn set_first_parent(&mut self, first_parent: bool) {
+        self.first_parent = first_parent;
+    }
+
+    pub fn first_parent(&self) -> bool {
+        self.first_parent
+    }
+
+    /// The git directory shared by all worktrees (`.git` for linked worktrees too).
+    pub fn common_dir(&self) -> PathBuf {
+        common_dir(&self.repo)
+    }
+
+    pub fn objects_dir(&self) -> PathBuf {
+        self.common_dir().join("objects")
+    }
+
+    /// Boundary commits of a shallow clone, as listed in `.git/shallow`.
+    pub fn shallow_commits(&self) -> Result<HashSet<git2::Oid>> {
+        read_oid_list(&self.common_dir().join("shallow"))
+    }
+
+    /// Commits whose parents are rewritten by the legacy `.git/info/grafts` file.
+    pub fn grafted_commits(&self) -> Result<HashSet<git2::Oid>> {
+        read_oid_list(&self.common_dir().join("info").join("grafts"))
+    }
+
     pub fn head(&self) -> Result<Reference<'_>> {
         Ok(self.repo.head()?)
     }
@@ -52,23 +103,150 @@ impl GitRepo {
     }
 
     pub fn walk_commits(&self) -> Result<impl Iterator<Item = Result<Commit<'_>>>> {
+        Ok(self.history()?.map(move |oid_result| match oid_result {
+            Ok(oid) => self.repo.find_commit(oid).map_err(VcsqlError::Git),
+            Err(e) => Err(VcsqlError::Git(e)),
+        }))
+    }
+
+    /// The same history as `walk_commits` as bare ids, children before
+    /// parents. With a commit-graph the walk reads only the graph, falling
+    /// back to commit objects for commits written after it.
+    pub fn walk_commit_ids(&self, graph: Option<&CommitGraph>) -> Result<Vec<Oid>> {
+        let head = self.head_commit()?.id();
+        self.walk_commit_ids_from(graph, &[head])
+    }
+
+    /// Like `walk_commit_ids`, but for the history of every commit in `starts`.
+    pub fn walk_commit_ids_from(
+        &self,
+        graph: Option<&CommitGraph>,
+        starts: &[Oid],
+    ) -> Result<Vec<Oid>> {
+        let Some(graph) = graph else {
+            let mut revwalk = self.repo.revwalk()?;
+            for start in starts {
+                revwalk.push(*start)?;
+            }
+            revwalk.set_sorting(git2::Sort::TIME | git2::Sort::TOPOLOGICAL)?;
+            if self.first_parent {
+                revwalk.simplify_first_parent()?;
+            }
+            let mut oids = Vec::new();
+            for oid in revwalk {
+                oids.push(oid?);
+            }
+            return Ok(oids);
+        };
+
+        let mut nodes: HashMap<Oid, (i64, Vec<Oid>)> = HashMap::new();
+        let mut pending_children: HashMap<Oid, usize> = HashMap::new();
+        let mut stack = starts.to_vec();
+        while let Some(oid) = stack.pop() {
+            if nodes.contains_key(&oid) {
+                continue;
+            }
+            let (time, mut parents) = match graph.commit(oid) {
+                Some(commit) => (commit.commit_time, commit.parent_ids),
+                None => {
+                    let commit = self.repo.find_commit(oid)?;
+                    (commit.time().seconds(), commit.parent_ids().collect())
+                }
+            };
+            if self.first_parent {
+                parents.truncate(1);
+            }
+            for parent in &parents {
+                *pending_children.entry(*parent).or_insert(0) += 1;
+                stack.push(*parent);
+            }
+            nodes.insert(oid, (time, parents));
+        }
+
+        // Newest first among commits whose children have all been emitted
+        let mut ready: BinaryHeap<(i64, Oid)> = BinaryHeap::new();
+        let unique_starts: HashSet<Oid> = starts.iter().copied().collect();
+        for start in unique_starts {
+            if !pending_children.contains_key(&start) {
+                ready.push((nodes[&start].0, start));
+            }
+        }
+        let mut oids = Vec::with_capacity(nodes.len());
+        while let Some((_, oid)) = ready.pop() {
+            oids.push(oid);
+            for parent in &nodes[&oid].1 {
+                let pending = pending_children.get_mut(parent).expect("counted above");
+                *pending -= 1;
+                if *pending == 0 {
+                    ready.push((nodes[parent].0, *parent));
+                }
+            }
+        }
+
+        Ok(oids)
+    }
+
+    fn history(&self) -> Result<Revwalk<'_>> {
         let mut revwalk = self.repo.revwalk()?;
         revwalk.push_head()?;
         revwalk.set_sorting(git2::Sort::TIME | git2::Sort::TOPOLOGICAL)?;
+        if self.first_parent {
+            revwalk.simplify_first_parent()?;
+        }
+        Ok(revwalk)
+    }
 
-        Ok(revwalk.filter_map(move |oid_result| match oid_result {
-            Ok(oid) => match self.repo.find_commit(oid) {
-                Ok(commit) => Some(Ok(commit)),
-                Err(e) => Some(Err(VcsqlError::Git(e))),
-            },
-            Err(e) => Some(Err(VcsqlError::Git(e))),
-        }))
+    /// The commit-graph file(s), when present and trustworthy. Like git, they
+    /// are ignored when shallow or graft files rewrite parents.
+    pub fn commit_graph(&self) -> Result<Option<CommitGraph>> {
+        if !self.shallow_commits()?.is_empty() || !self.grafted_commits()?.is_empty() {
+            return Ok(None);
+        }
+        let enabled = self
+            .repo
+            .config()
+            .ok()
+            .and_then(|c| c.get_bool("core.commitGraph").ok())
+            .unwrap_or(true);
+        if !enabled {
+            return Ok(None);
+        }
+        Ok(CommitGraph::open(&self.objects_dir()))
+    }
+
+    /// Parent ids of `oid`, from the commit-graph when it covers the commit.
+    pub fn parent_ids(&self, graph: Option<&CommitGraph>, oid: Oid) -> Result<Vec<Oid>> {
+        if let Some(commit) = graph.and_then(|g| g.commit(oid)) {
+            return Ok(commit.parent_ids);
+        }
+        Ok(self.repo.find_commit(oid)?.parent_ids().collect())
     }
 
     pub fn branches(&self, branch_type: Option<BranchType>) -> Result<git2::Branches<'_>> {
         Ok(self.repo.branches(branch_type)?)
     }
 
+    /// The repository's main line of development: whatever `origin/HEAD`
+    /// points at, else `init.defaultBranch`, else a local `main` or `master`.
+    pub fn default_branch(&self) -> Option<String> {
+        if let Ok(origin_head) = self.repo.find_reference("refs/remotes/origin/HEAD") {
+            if let Some(target) = origin_head.symbolic_target() {
+                return Some(target.to_string());
+            }
+        }
+
+        let configured = self
+            .repo
+            .config()
+            .ok()
+            .and_then(|c| c.get_string("init.defaultBranch").ok());
+        configured
+            .into_iter()
+            .chain(["main".to_string(), "master".to_string()])
+            .map(|name| format!("refs/heads/{}", name))
+            .find(|refname| self.repo.find_reference(refname).is_ok())
+    }
+
     pub fn is_head_detached(&self) -> bool {
         self.repo.head_detached().unwrap_or(false)
     }
@@ -81,3 +259,49 @@ impl GitRepo {
         Ok(self.repo.graph_ahead_behind(local, upstream)?)
     }
 }
+
+/// The git directory shared by all worktrees of `repo`.
+pub fn common_dir(repo: &Repository) -> PathBuf {
+    let git_dir = repo.path();
+    match std::fs::read_to_string(git_dir.join("commondir")) {
+        Ok(contents) => git_dir.join(contents.trim()),
+        Err(_) => git_dir.to_path_buf(),
+    }
+}
+
+/// A per-user git file named by config `key` (such as `core.excludesFile`),
+/// falling back to `$XDG_CONFIG_HOME/git/<name>` like git itself.
+pub fn global_config_file(repo: &Repository, key: &str, name: &str) -> Option<PathBuf> {
+    if let Ok(path) = repo.config().and_then(|c| c.get_path(key)) {
+        return Some(path);
+    }
+    let config_home = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".config")))?;
+    Some(config_home.join("git").join(name))
+}
+
+/// Whether a merge only carries over the entry at `path` from a merged branch:
+/// a parent other than the first already has `id` there (`None` for absent).
+/// Diffing a merge against its first parent would otherwise report the
+/// branch's change a second time.
+pub fn merged_from_side(commit: &Commit, path: &Path, id: Option<Oid>) -> bool {
+    commit.parents().skip(1).any(|parent| {
+        let entry = parent.tree().ok().and_then(|tree| tree.get_path(path).ok());
+        entry.map(|entry| entry.id()) == id
+    })
+}
+
+/// Read the first object id from each line of a file such as `.git/shallow`.
+fn read_oid_list(path: &Path) -> Result<HashSet<git2::Oid>> {
+    let mut oids = HashSet::new();
+    if !path.is_file() {
+        return Ok(oids);
+    }
+    for line in std::fs::read_to_string(path)?.lines() {
+        if let Some(Ok(oid)) = line.split_whitespace().next().map(git2::Oid::from_str) {
+            oids.insert(oid);
+        }
+    }
+    Ok(oids)
+}
@@ -5,7 +5,7 @@ use std::time::Instant;
 
 use vcsql::cli::{format_output, Args, Command};
 use vcsql::git::GitRepo;
-use vcsql::sql::engine::SqlEngine;
+use vcsql::sql::engine::{EngineOptions, SqlEngine};
 use vcsql::sql::schema::{get_table_info, get_tables_by_category, TABLES};
 
 fn main() -> Result<()> {
@@ -33,15 +33,35 @@ fn main() -> Result<()> {
 fn execute_query(args: &Args, sql: &str) -> Result<()> {
     let start = Instant::now();
 
-    let mut engine = SqlEngine::new()?;
+    let mut engine = SqlEngine::with_options(EngineOptions {
+        include_ignored: args.include_ignored,
+        base_branch: args.base.clone(),
+        reference_patterns: args.reference_patterns.clone(),
+        first_parent: args.first_parent,
+        coupling_min_count: args.coupling_min_count,
+        coupling_max_files: args.coupling_max_files,
+        codeowners_rev: args.codeowners_rev.clone(),
+        todo_tags: args.todo_tags.clone(),
+        todos_rev: args.todos_rev.clone(),
+    })?;
 
     for repo_path in &args.repo {
-        let mut repo = GitRepo::open(repo_path)
+        let repo = GitRepo::open(repo_path)
             .with_context(|| format!("Failed to open repository: {}", repo_path.display()))?;
 
-        engine
-            .load_tables_for_query(sql, &mut repo)
-            .with_context(|| "Failed to load tables")?;
+        let mut repos = Vec::new();
+        if args.recurse_submodules {
+            repos.extend(repo.open_submodules().with_context(|| {
+                format!("Failed to open submodules of: {}", repo_path.display())
+            })?);
+        }
+        repos.insert(0, repo);
+
+        for repo in &mut repos {
+            engine
+                .load_tables_for_query(sql, repo)
+                .with_context(|| format!("Failed to load tables for: {}", repo.path()))?;
+        }
     }
 
     let result = engine.execute(sql).with_context(|| "Query execution failed")?;
@@ -65,7 +85,7 @@ fn show_tables() -> Result<()> {
     println!("\nAvailable tables:\n");
 
     let categories = get_tables_by_category();
-    let category_order = ["CORE", "REFERENCES", "CHANGES", "CONFIGURATION", "WORKING DIRECTORY", "OPERATIONAL", "COMPUTED"];
+    let category_order = ["CORE", "REFERENCES", "CHANGES", "CONFIGURATION", "WORKING DIRECTORY", "OPERATIONAL", "STORAGE", "COMPUTED"];
 
     for category in category_order {
         if let Some(tables) = categories.get(category) {
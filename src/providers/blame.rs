@@ -1,4 +1,6 @@
 use crate::error::Result;
+use crate::git::blame::{blame_lines, head_paths};
+use crate::git::language::Classifier;
 use crate::git::GitRepo;
 use crate::providers::Provider;
 use chrono::{TimeZone, Utc};
@@ -24,99 +26,47 @@ impl Provider for BlameProvider {
             r#"
             INSERT INTO blame (
                 path, line_number, commit_id, original_line, original_path,
-                author_name, author_email, authored_at, line_content, repo
-            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+                author_name, author_email, authored_at, line_content,
+                language, category, repo
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)
             "#,
         )?;
 
         let repo_path = repo.path().to_string();
         let git_repo = repo.inner();
 
-        // If no path filter, we need to blame all files in HEAD
-        // This can be expensive, so we'll limit to tracked files
+        // Without a path filter every file in HEAD is blamed, which can be expensive
         let paths_to_blame: Vec<String> = if let Some(ref filter) = self.path_filter {
             vec![filter.clone()]
         } else {
-            // Get all files from HEAD tree
-            if let Ok(head) = git_repo.head() {
-                if let Ok(tree) = head.peel_to_tree() {
-                    let mut paths = Vec::new();
-                    tree.walk(git2::TreeWalkMode::PreOrder, |dir, entry| {
-                        if entry.kind() == Some(git2::ObjectType::Blob) {
-                            let path = if dir.is_empty() {
-                                entry.name().unwrap_or("").to_string()
-                            } else {
-                                format!("{}{}", dir, entry.name().unwrap_or(""))
-                            };
-                            paths.push(path);
-                        }
-                        git2::TreeWalkResult::Ok
-                    })?;
-                    paths
-                } else {
-                    Vec::new()
-                }
-            } else {
-                Vec::new()
-            }
+            head_paths(git_repo)?
         };
 
-        for path in paths_to_blame {
-            if let Ok(blame) = git_repo.blame_file(std::path::Path::new(&path), None) {
-                // Read file content to get line text
-                let file_content = if let Ok(head) = git_repo.head() {
-                    if let Ok(tree) = head.peel_to_tree() {
-                        if let Ok(entry) = tree.get_path(std::path::Path::new(&path)) {
-                            if let Ok(blob) = git_repo.find_blob(entry.id()) {
-                                if !blob.is_binary() {
-                                    String::from_utf8_lossy(blob.content()).to_string()
-                                } else {
-                                    String::new()
-                                }
-                            } else {
-                                String::new()
-                            }
-                        } else {
-                            String::new()
-                        }
-                    } else {
-                        String::new()
-                    }
-                } else {
-                    String::new()
-                };
-
-                let lines: Vec<&str> = file_content.lines().collect();
-
-                for (line_idx, hunk) in blame.iter().enumerate() {
-                    let line_number = (line_idx + 1) as i64;
-                    let commit_id = hunk.final_commit_id().to_string();
-                    let original_line = hunk.orig_start_line() as i64;
-                    let original_path = hunk
-                        .path()
-                        .map(|p| p.to_string_lossy().to_string())
-                        .unwrap_or_else(|| path.clone());
+        let mut classifier = Classifier::new(git_repo);
 
-                    let sig = hunk.final_signature();
-                    let author_name = sig.name().unwrap_or("").to_string();
-                    let author_email = sig.email().unwrap_or("").to_string();
-                    let authored_at = format_git_time(sig.when());
-
-                    let line_content = lines.get(line_idx).unwrap_or(&"").to_string();
-
-                    stmt.execute((
-                        &path,
-                        line_number,
-                        &commit_id,
-                        original_line,
-                        &original_path,
-                        &author_name,
-                        &author_email,
-                        &authored_at,
-                        &line_content,
-                        &repo_path,
-                    ))?;
-                }
+        for path in paths_to_blame {
+            let Some(lines) = blame_lines(git_repo, &path, None, false) else {
+                continue;
+            };
+            // Only the first line is needed, for a shebang
+            let classification = classifier.classify(&path, || {
+                lines.first().map(|line| line.content.clone().into_bytes())
+            });
+            for line in lines {
+                stmt.execute((
+                    &path,
+                    line.line_number as i64,
+                    line.commit_id.to_string(),
+                    line.original_line as i64,
+                    &line.original_path,
+                    &line.author_name,
+                    &line.author_email,
+                    format_git_time(line.authored_at),
+                    &line.content,
+                    &classification.language,
+                    classification.category,
+                    &repo_path,
+                ))?;
             }
         }
 
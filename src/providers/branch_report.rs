@@ -0,0 +1,185 @@
+use crate::error::Result;
+use crate::git::diff::diff_options;
+use crate::git::patch_id::{commit_patch_id, diff_patch_id};
+use crate::git::GitRepo;
+use crate::providers::Provider;
+use chrono::{TimeZone, Utc};
+use git2::{BranchType, Oid, Repository};
+use rusqlite::Connection;
+use std::collections::{HashMap, HashSet};
+
+pub struct BranchReportProvider {
+    /// Branch or revision to compare against; the default branch when unset
+    pub base: Option<String>,
+}
+
+impl BranchReportProvider {
+    pub fn new(base: Option<String>) -> Self {
+        Self { base }
+    }
+}
+
+impl Provider for BranchReportProvider {
+    fn table_name(&self) -> &'static str {
+        "branch_report"
+    }
+
+    fn populate(&self, conn: &Connection, repo: &mut GitRepo) -> Result<()> {
+        let mut stmt = conn.prepare(
+            r#"
+            INSERT INTO branch_report (
+                name, full_name, is_remote, base, merge_base_id, ahead, behind,
+                is_merged, merged_via, last_commit_id, last_commit_at,
+                last_author_name, last_author_email, age_days, repo
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)
+            "#,
+        )?;
+
+        let repo_path = repo.path().to_string();
+        let git_repo = repo.inner();
+
+        let base_name = match &self.base {
+            Some(base) => base.clone(),
+            None => repo.default_branch().unwrap_or_else(|| "HEAD".to_string()),
+        };
+        let base_id = git_repo.revparse_single(&base_name)?.peel_to_commit()?.id();
+        let base_label = base_name
+            .strip_prefix("refs/heads/")
+            .or_else(|| base_name.strip_prefix("refs/remotes/"))
+            .unwrap_or(&base_name)
+            .to_string();
+
+        let now = Utc::now().timestamp();
+        let mut patch_ids: HashMap<Oid, Option<Oid>> = HashMap::new();
+
+        for branch_result in repo.branches(None)? {
+            let (branch, branch_type) = branch_result?;
+            let reference = branch.get();
+            // Symbolic refs like origin/HEAD would duplicate their target
+            if reference.kind() != Some(git2::ReferenceType::Direct) {
+                continue;
+            }
+            let Ok(tip) = reference.peel_to_commit() else {
+                continue;
+            };
+
+            let name = branch.name()?.unwrap_or("").to_string();
+            let full_name = reference.name().unwrap_or("").to_string();
+            let is_remote = matches!(branch_type, BranchType::Remote);
+
+            let merge_base = git_repo.merge_base(tip.id(), base_id).ok();
+            let (ahead, behind) = repo.graph_ahead_behind(tip.id(), base_id)?;
+
+            let merged_via = if ahead == 0 {
+                Some("ancestor")
+            } else if let Some(merge_base) = merge_base {
+                squash_merged(git_repo, tip.id(), base_id, merge_base, &mut patch_ids)?
+            } else {
+                None
+            };
+
+            let author = tip.author();
+            let last_commit_at = format_git_time(tip.time());
+            let age_days = (now - tip.time().seconds()).max(0) / 86_400;
+
+            stmt.execute((
+                &name,
+                &full_name,
+                if is_remote { 1 } else { 0 },
+                &base_label,
+                merge_base.map(|oid| oid.to_string()),
+                ahead as i64,
+                behind as i64,
+                if merged_via.is_some() { 1 } else { 0 },
+                merged_via,
+                tip.id().to_string(),
+                &last_commit_at,
+                author.name().unwrap_or(""),
+                author.email().unwrap_or(""),
+                age_days,
+                &repo_path,
+            ))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Whether the work on `tip` landed in `base` without a merge: either its
+/// combined change matches one base commit ("squash"), or every commit on
+/// the branch has a patch-equivalent twin in the base ("cherry-pick").
+fn squash_merged(
+    repo: &Repository,
+    tip: Oid,
+    base: Oid,
+    merge_base: Oid,
+    patch_ids: &mut HashMap<Oid, Option<Oid>>,
+) -> Result<Option<&'static str>> {
+    let mut landed: HashSet<Oid> = HashSet::new();
+    for oid in commits_between(repo, base, merge_base)? {
+        if let Some(patch_id) = cached_patch_id(repo, oid, patch_ids)? {
+            landed.insert(patch_id);
+        }
+    }
+    if landed.is_empty() {
+        return Ok(None);
+    }
+
+    let old_tree = repo.find_commit(merge_base)?.tree()?;
+    let new_tree = repo.find_commit(tip)?.tree()?;
+    let diff = repo.diff_tree_to_tree(Some(&old_tree), Some(&new_tree), Some(&mut diff_options()))?;
+    if let Some(combined) = diff_patch_id(&diff)? {
+        if landed.contains(&combined) {
+            return Ok(Some("squash"));
+        }
+    }
+
+    let mut all_landed = true;
+    let mut any = false;
+    for oid in commits_between(repo, tip, merge_base)? {
+        if let Some(patch_id) = cached_patch_id(repo, oid, patch_ids)? {
+            any = true;
+            if !landed.contains(&patch_id) {
+                all_landed = false;
+                break;
+            }
+        }
+    }
+
+    Ok(if any && all_landed { Some("cherry-pick") } else { None })
+}
+
+/// Commits reachable from `tip` but not from `stop`.
+fn commits_between(repo: &Repository, tip: Oid, stop: Oid) -> Result<Vec<Oid>> {
+    let mut revwalk = repo.revwalk()?;
+    revwalk.push(tip)?;
+    revwalk.hide(stop)?;
+    let mut oids = Vec::new();
+    for oid in revwalk {
+        oids.push(oid?);
+    }
+    Ok(oids)
+}
+
+fn cached_patch_id(
+    repo: &Repository,
+    oid: Oid,
+    cache: &mut HashMap<Oid, Option<Oid>>,
+) -> Result<Option<Oid>> {
+    if let Some(patch_id) = cache.get(&oid) {
+        return Ok(*patch_id);
+    }
+    let commit = repo.find_commit(oid)?;
+    let patch_id = commit_patch_id(repo, &commit)?;
+    cache.insert(oid, patch_id);
+    Ok(patch_id)
+}
+
+fn format_git_time(time: git2::Time) -> String {
+    let timestamp = time.seconds();
+    if let Some(dt) = Utc.timestamp_opt(timestamp, 0).single() {
+        dt.format("%Y-%m-%d %H:%M:%S").to_string()
+    } else {
+        timestamp.to_string()
+    }
+}
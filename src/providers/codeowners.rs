@@ -0,0 +1,58 @@
+use crate::error::Result;
+use crate::git::codeowners::{find_codeowners, parse_codeowners};
+use crate::git::GitRepo;
+use crate::providers::Provider;
+use rusqlite::Connection;
+
+pub struct CodeownersProvider {
+    /// Revision to read CODEOWNERS from; HEAD when unset
+    pub rev: Option<String>,
+}
+
+impl CodeownersProvider {
+    pub fn new(rev: Option<String>) -> Self {
+        Self { rev }
+    }
+}
+
+impl Provider for CodeownersProvider {
+    fn table_name(&self) -> &'static str {
+        "codeowners"
+    }
+
+    fn populate(&self, conn: &Connection, repo: &mut GitRepo) -> Result<()> {
+        let mut stmt = conn.prepare(
+            r#"
+            INSERT INTO codeowners (
+                source, line_number, pattern, owners, owner_count, repo
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+            "#,
+        )?;
+
+        let repo_path = repo.path().to_string();
+        let git_repo = repo.inner();
+
+        // An unborn HEAD has no CODEOWNERS yet
+        if self.rev.is_none() && git_repo.head().is_err() {
+            return Ok(());
+        }
+        let Some((source, blob_id)) = find_codeowners(git_repo, self.rev.as_deref())? else {
+            return Ok(());
+        };
+        let blob = git_repo.find_blob(blob_id)?;
+
+        for rule in parse_codeowners(&String::from_utf8_lossy(blob.content())) {
+            let owners = (!rule.owners.is_empty()).then(|| rule.owners.join(" "));
+            stmt.execute((
+                source,
+                rule.line_number as i64,
+                &rule.text,
+                owners,
+                rule.owners.len() as i64,
+                &repo_path,
+            ))?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+use crate::error::Result;
+use crate::git::reachability::containment;
+use crate::git::GitRepo;
+use crate::providers::Provider;
+use git2::{BranchType, Oid};
+use rusqlite::Connection;
+
+pub struct CommitBranchesProvider;
+
+impl Provider for CommitBranchesProvider {
+    fn table_name(&self) -> &'static str {
+        "commit_branches"
+    }
+
+    fn populate(&self, conn: &Connection, repo: &mut GitRepo) -> Result<()> {
+        let mut stmt = conn.prepare(
+            r#"
+            INSERT INTO commit_branches (
+                commit_id, branch, full_name, is_remote, repo
+            ) VALUES (?1, ?2, ?3, ?4, ?5)
+            "#,
+        )?;
+
+        let repo_path = repo.path().to_string();
+
+        // (name, full_name, is_remote) alongside the matching tip commit
+        let mut branches: Vec<(String, String, bool)> = Vec::new();
+        let mut tips: Vec<Oid> = Vec::new();
+        for branch_result in repo.branches(None)? {
+            let (branch, branch_type) = branch_result?;
+            let reference = branch.get();
+            // Symbolic refs like origin/HEAD would duplicate their target
+            if reference.kind() != Some(git2::ReferenceType::Direct) {
+                continue;
+            }
+            let Ok(commit) = reference.peel_to_commit() else {
+                continue;
+            };
+            let name = branch.name()?.unwrap_or("").to_string();
+            let full_name = reference.name().unwrap_or("").to_string();
+            branches.push((name, full_name, matches!(branch_type, BranchType::Remote)));
+            tips.push(commit.id());
+        }
+
+        let sets = containment(repo.inner(), &tips)?;
+
+        for (oid, set) in &sets {
+            let commit_id = oid.to_string();
+            for index in set.iter() {
+                let (name, full_name, is_remote) = &branches[index];
+                stmt.execute((
+                    &commit_id,
+                    name,
+                    full_name,
+                    if *is_remote { 1 } else { 0 },
+                    &repo_path,
+                ))?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,121 @@
+use crate::error::Result;
+use crate::git::reachability::ref_roots;
+use crate::git::GitRepo;
+use crate::providers::Provider;
+use git2::Oid;
+use rusqlite::Connection;
+use std::collections::{HashMap, HashSet};
+
+pub struct CommitGraphProvider;
+
+impl Provider for CommitGraphProvider {
+    fn table_name(&self) -> &'static str {
+        "commit_graph"
+    }
+
+    fn populate(&self, conn: &Connection, repo: &mut GitRepo) -> Result<()> {
+        let mut stmt = conn.prepare(
+            r#"
+            INSERT INTO commit_graph (
+                commit_id, generation, root_distance, child_count,
+                is_branch_point, is_tip, merge_depth, repo
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+            "#,
+        )?;
+
+        let repo_path = repo.path().to_string();
+
+        let graph = repo.commit_graph()?;
+
+        // Every branch, local and remote, so their heads show up as tips
+        let starts: Vec<Oid> = ref_roots(repo)?
+            .into_iter()
+            .filter(|(name, _)| {
+                name == "HEAD"
+                    || name.starts_with("refs/heads/")
+                    || name.starts_with("refs/remotes/")
+            })
+            .map(|(_, oid)| oid)
+            .filter(|oid| repo.inner().find_commit(*oid).is_ok())
+            .collect();
+        let head = repo.inner().head().ok().and_then(|h| h.target());
+        // Branch heads are tips even when another branch builds on them
+        let tips: HashSet<Oid> = starts.iter().copied().collect();
+
+        // Children come before their parents in the walk
+        let mut order: Vec<(Oid, Vec<Oid>)> = Vec::new();
+        for oid in repo.walk_commit_ids_from(graph.as_ref(), &starts)? {
+            order.push((oid, repo.parent_ids(graph.as_ref(), oid)?));
+        }
+        let index: HashMap<Oid, usize> = order
+            .iter()
+            .enumerate()
+            .map(|(i, (oid, _))| (*oid, i))
+            .collect();
+
+        // Parents outside the loaded history (shallow boundaries, --first-parent) are ignored
+        let parents: Vec<Vec<(usize, usize)>> = order
+            .iter()
+            .map(|(_, ids)| {
+                ids.iter()
+                    .enumerate()
+                    .filter_map(|(n, id)| index.get(id).map(|&p| (n, p)))
+                    .collect()
+            })
+            .collect();
+
+        let len = order.len();
+        let mut child_count = vec![0i64; len];
+        // Measured from HEAD; commits only on other branches have none
+        let mut merge_depth = vec![i64::MAX; len];
+        if let Some(&i) = head.as_ref().and_then(|oid| index.get(oid)) {
+            merge_depth[i] = 0;
+        }
+        for (i, commit_parents) in parents.iter().enumerate() {
+            for &(n, p) in commit_parents {
+                child_count[p] += 1;
+                // Leaving the first-parent line through a merge goes one level deeper
+                let depth = merge_depth[i].saturating_add(if n > 0 { 1 } else { 0 });
+                merge_depth[p] = merge_depth[p].min(depth);
+            }
+        }
+
+        // Generations come from the commit-graph where it has them; only newer
+        // commits are computed. --first-parent drops merge parents, so there
+        // every level is computed from the loaded parents
+        let stored_generation = |oid: Oid| {
+            graph
+                .as_ref()
+                .filter(|_| !repo.first_parent())
+                .and_then(|g| g.commit(oid))
+                .and_then(|commit| commit.generation)
+        };
+        let mut generation = vec![1i64; len];
+        let mut root_distance = vec![0i64; len];
+        for i in (0..len).rev() {
+            if let Some(stored) = stored_generation(order[i].0) {
+                generation[i] = stored as i64;
+            } else if let Some(max) = parents[i].iter().map(|&(_, p)| generation[p]).max() {
+                generation[i] = max + 1;
+            }
+            if let Some(min) = parents[i].iter().map(|&(_, p)| root_distance[p]).min() {
+                root_distance[i] = min + 1;
+            }
+        }
+
+        for (i, (oid, _)) in order.iter().enumerate() {
+            stmt.execute((
+                oid.to_string(),
+                generation[i],
+                root_distance[i],
+                child_count[i],
+                if child_count[i] > 1 { 1 } else { 0 },
+                if tips.contains(oid) { 1 } else { 0 },
+                (merge_depth[i] != i64::MAX).then_some(merge_depth[i]),
+                &repo_path,
+            ))?;
+        }
+
+        Ok(())
+    }
+}
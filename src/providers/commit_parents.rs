@@ -20,12 +20,13 @@ impl Provider for CommitParentsProvider {
 
         let repo_path = repo.path().to_string();
 
-        for commit_result in repo.walk_commits()? {
-            let commit = commit_result?;
-            let commit_id = commit.id().to_string();
+        let graph = repo.commit_graph()?;
 
-            for (index, parent) in commit.parents().enumerate() {
-                let parent_id = parent.id().to_string();
+        for oid in repo.walk_commit_ids(graph.as_ref())? {
+            let commit_id = oid.to_string();
+
+            for (index, parent) in repo.parent_ids(graph.as_ref(), oid)?.iter().enumerate() {
+                let parent_id = parent.to_string();
                 stmt.execute((&commit_id, &parent_id, index as i64, &repo_path))?;
             }
         }
@@ -0,0 +1,141 @@
+use crate::error::{Result, VcsqlError};
+use crate::git::GitRepo;
+use crate::providers::Provider;
+use regex::Regex;
+use rusqlite::Connection;
+use std::collections::HashSet;
+
+/// Patterns every repository gets, as `(kind, regex)`. Named groups `key`,
+/// `number` and `branch` fill the matching columns; without a `key` group
+/// the whole match is the key.
+const BUILTIN_PATTERNS: &[(&str, &str)] = &[
+    // GitHub merge button
+    (
+        "pull_request",
+        r"\AMerge pull request (?P<key>#(?P<number>\d+)) from (?P<branch>\S+)",
+    ),
+    // GitHub squash and rebase merges append "(#123)" to the summary
+    (
+        "pull_request",
+        r"\A[^\n]*\((?P<key>#(?P<number>\d+))\)[ \t]*(?:\n|\z)",
+    ),
+    // GitLab merge commits
+    (
+        "merge_request",
+        r"(?s)\A(?:Merge branch '(?P<branch>[^']+)'.*)?See merge request (?P<key>\S*!(?P<number>\d+))",
+    ),
+    // Closing keywords understood by GitHub and GitLab
+    (
+        "fixes",
+        r"(?i)\b(?:close[sd]?|fix(?:e[sd])?|resolve[sd]?):?\s+(?P<key>(?:[\w.-]+/[\w.-]+)?#(?P<number>\d+))\b",
+    ),
+    ("jira", r"\b(?P<key>[A-Z][A-Z0-9]+-(?P<number>\d+))\b"),
+];
+
+/// Standards names that look like JIRA keys ("UTF-8", "RFC-2822").
+const NOT_JIRA_PROJECTS: &[&str] = &["AES", "CVE", "ISO", "RFC", "SHA", "UTF", "X86"];
+
+pub struct CommitReferencesProvider {
+    /// Extra `kind=regex` patterns, applied after the built-in ones
+    pub patterns: Vec<String>,
+}
+
+impl CommitReferencesProvider {
+    pub fn new(patterns: Vec<String>) -> Self {
+        Self { patterns }
+    }
+}
+
+impl Provider for CommitReferencesProvider {
+    fn table_name(&self) -> &'static str {
+        "commit_references"
+    }
+
+    fn populate(&self, conn: &Connection, repo: &mut GitRepo) -> Result<()> {
+        let mut stmt = conn.prepare(
+            r#"
+            INSERT INTO commit_references (
+                commit_id, kind, key, number, source_branch, repo
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+            "#,
+        )?;
+
+        let repo_path = repo.path().to_string();
+
+        let mut patterns: Vec<(String, Regex)> = Vec::new();
+        for (kind, pattern) in BUILTIN_PATTERNS {
+            patterns.push((kind.to_string(), Regex::new(pattern).unwrap()));
+        }
+        // Per-repository patterns from `git config --add vcsql.referencePattern kind=regex`
+        let mut configured: Vec<String> = Vec::new();
+        if let Ok(config) = repo.inner().config() {
+            if let Ok(mut entries) = config.multivar("vcsql.referencePattern", None) {
+                while let Some(Ok(entry)) = entries.next() {
+                    if let Some(value) = entry.value() {
+                        configured.push(value.to_string());
+                    }
+                }
+            }
+        }
+        for spec in configured.iter().chain(&self.patterns) {
+            patterns.push(parse_pattern(spec)?);
+        }
+
+        for commit_result in repo.walk_commits()? {
+            let commit = commit_result?;
+            let commit_id = commit.id().to_string();
+            let message = commit.message().unwrap_or("");
+
+            let mut seen: HashSet<(String, String)> = HashSet::new();
+            for (kind, regex) in &patterns {
+                for caps in regex.captures_iter(message) {
+                    let key = caps
+                        .name("key")
+                        .or_else(|| caps.get(0))
+                        .map(|m| m.as_str().to_string())
+                        .unwrap_or_default();
+                    if kind == "jira" && is_standard_name(&key) {
+                        continue;
+                    }
+                    if !seen.insert((kind.clone(), key.clone())) {
+                        continue;
+                    }
+
+                    let number = caps
+                        .name("number")
+                        .and_then(|m| m.as_str().parse::<i64>().ok())
+                        .or_else(|| trailing_number(&key));
+                    let branch = caps.name("branch").map(|m| m.as_str().to_string());
+
+                    stmt.execute((&commit_id, kind, &key, number, &branch, &repo_path))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Split a `kind=regex` pattern specification.
+fn parse_pattern(spec: &str) -> Result<(String, Regex)> {
+    let (kind, pattern) = spec
+        .split_once('=')
+        .filter(|(kind, _)| !kind.is_empty())
+        .ok_or_else(|| {
+            VcsqlError::Other(format!("reference pattern must be KIND=REGEX: {}", spec))
+        })?;
+    let regex = Regex::new(pattern)
+        .map_err(|e| VcsqlError::Other(format!("invalid reference pattern '{}': {}", spec, e)))?;
+    Ok((kind.trim().to_string(), regex))
+}
+
+fn is_standard_name(key: &str) -> bool {
+    key.split('-')
+        .next()
+        .is_some_and(|project| NOT_JIRA_PROJECTS.contains(&project))
+}
+
+fn trailing_number(key: &str) -> Option<i64> {
+    let digits = key.len() - key.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+    key[key.len() - digits..].parse().ok()
+}
@@ -0,0 +1,55 @@
+use crate::error::Result;
+use crate::git::reachability::containment;
+use crate::git::GitRepo;
+use crate::providers::Provider;
+use git2::Oid;
+use rusqlite::Connection;
+
+pub struct CommitTagsProvider;
+
+impl Provider for CommitTagsProvider {
+    fn table_name(&self) -> &'static str {
+        "commit_tags"
+    }
+
+    fn populate(&self, conn: &Connection, repo: &mut GitRepo) -> Result<()> {
+        let mut stmt = conn.prepare(
+            r#"
+            INSERT INTO commit_tags (
+                commit_id, tag, full_name, repo
+            ) VALUES (?1, ?2, ?3, ?4)
+            "#,
+        )?;
+
+        let repo_path = repo.path().to_string();
+        let git_repo = repo.inner();
+
+        // (name, full_name) alongside the tagged commit; tags of trees or blobs are skipped
+        let mut tags: Vec<(String, String)> = Vec::new();
+        let mut tips: Vec<Oid> = Vec::new();
+        for reference in git_repo.references_glob("refs/tags/*")?.flatten() {
+            let Ok(commit) = reference.peel_to_commit() else {
+                continue;
+            };
+            let full_name = reference.name().unwrap_or("").to_string();
+            let name = full_name
+                .strip_prefix("refs/tags/")
+                .unwrap_or(&full_name)
+                .to_string();
+            tags.push((name, full_name));
+            tips.push(commit.id());
+        }
+
+        let sets = containment(git_repo, &tips)?;
+
+        for (oid, set) in &sets {
+            let commit_id = oid.to_string();
+            for index in set.iter() {
+                let (name, full_name) = &tags[index];
+                stmt.execute((&commit_id, name, full_name, &repo_path))?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,8 +1,9 @@
 use crate::error::Result;
+use crate::git::mainline::Mainline;
 use crate::git::GitRepo;
 use crate::providers::Provider;
 use chrono::{TimeZone, Utc};
-use rusqlite::Connection;
+use rusqlite::{params, Connection};
 
 pub struct CommitsProvider;
 
@@ -19,14 +20,18 @@ impl Provider for CommitsProvider {
                 author_name, author_email, authored_at,
                 committer_name, committer_email, committed_at,
                 message, summary, body,
-                parent_count, is_merge, repo
+                parent_count, is_merge, is_shallow_boundary,
+                is_mainline, mainline_depth, merged_in_by, repo
             ) VALUES (
-                ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15
+                ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16,
+                ?17, ?18, ?19
             )
             "#,
         )?;
 
         let repo_path = repo.path().to_string();
+        let shallow = repo.shallow_commits()?;
+        let mainline = Mainline::of_head(repo.inner())?;
 
         for commit_result in repo.walk_commits()? {
             let commit = commit_result?;
@@ -54,8 +59,14 @@ impl Provider for CommitsProvider {
 
             let parent_count = commit.parent_count() as i64;
             let is_merge = if parent_count > 1 { 1 } else { 0 };
+            let is_shallow_boundary = shallow.contains(&commit.id());
+            let mainline_depth = mainline.depth.get(&commit.id()).copied();
+            let merged_in_by = mainline
+                .merged_in_by
+                .get(&commit.id())
+                .map(|oid| oid.to_string());
 
-            stmt.execute((
+            stmt.execute(params![
                 &id,
                 short_id,
                 &tree_id,
@@ -70,8 +81,12 @@ impl Provider for CommitsProvider {
                 &body,
                 parent_count,
                 is_merge,
+                if is_shallow_boundary { 1 } else { 0 },
+                if mainline_depth.is_some() { 1 } else { 0 },
+                mainline_depth,
+                &merged_in_by,
                 &repo_path,
-            ))?;
+            ])?;
         }
 
         Ok(())
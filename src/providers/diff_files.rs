@@ -1,8 +1,14 @@
 use crate::error::Result;
+use crate::git::attributes::{AttributesFinder, TreeAttributes};
+use crate::git::diff::parent_diffs;
+use crate::git::language::Classifier;
+use crate::git::lfs::inspect_blob;
 use crate::git::GitRepo;
 use crate::providers::Provider;
-use git2::{Delta, DiffOptions};
+use git2::{Delta, Odb, Oid};
 use rusqlite::Connection;
+use std::collections::HashMap;
+use std::rc::Rc;
 
 pub struct DiffFilesProvider;
 
@@ -16,38 +22,45 @@ impl Provider for DiffFilesProvider {
             r#"
             INSERT INTO diff_files (
                 commit_id, parent_id, old_path, new_path, status,
-                insertions, deletions, is_binary, similarity, repo
-            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+                insertions, deletions, is_binary, similarity,
+                is_lfs, old_size, new_size, language, category, repo
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)
             "#,
         )?;
 
         let repo_path = repo.path().to_string();
         let git_repo = repo.inner();
 
-        let mut diff_opts = DiffOptions::new();
-        diff_opts.include_untracked(false);
+        // linguist overrides as of each commit, with one classifier per distinct
+        // set of .gitattributes files
+        let mut finder = AttributesFinder::default();
+        let mut classifiers: HashMap<Rc<[(String, Oid)]>, Classifier> = HashMap::new();
+        let odb = git_repo.odb()?;
 
         for commit_result in repo.walk_commits()? {
             let commit = commit_result?;
             let commit_id = commit.id().to_string();
-            let tree = commit.tree()?;
-
-            if commit.parent_count() == 0 {
-                // Root commit
-                let diff = git_repo.diff_tree_to_tree(None, Some(&tree), Some(&mut diff_opts))?;
-                insert_diff_files(&mut stmt, &diff, &commit_id, None, &repo_path)?;
-            } else {
-                for parent in commit.parents() {
-                    let parent_id = parent.id().to_string();
-                    let parent_tree = parent.tree()?;
-
-                    let diff = git_repo.diff_tree_to_tree(
-                        Some(&parent_tree),
-                        Some(&tree),
-                        Some(&mut diff_opts),
-                    )?;
-                    insert_diff_files(&mut stmt, &diff, &commit_id, Some(&parent_id), &repo_path)?;
-                }
+            let tree_files = finder.find(git_repo, &commit.tree()?)?;
+            let classifier = classifiers.entry(tree_files.clone()).or_insert_with(|| {
+                Classifier::with_attributes(
+                    git_repo,
+                    TreeAttributes::from_tree_files(git_repo, &tree_files),
+                )
+            });
+
+            // One set of rows per parent (only the first with --first-parent); a
+            // root commit is diffed against the empty tree
+            for (parent_id, diff) in parent_diffs(git_repo, &commit, repo.first_parent())? {
+                let parent_id = parent_id.map(|id| id.to_string());
+                insert_diff_files(
+                    &mut stmt,
+                    &odb,
+                    classifier,
+                    &diff,
+                    &commit_id,
+                    parent_id.as_deref(),
+                    &repo_path,
+                )?;
             }
         }
 
@@ -57,6 +70,8 @@ impl Provider for DiffFilesProvider {
 
 fn insert_diff_files(
     stmt: &mut rusqlite::Statement,
+    odb: &Odb,
+    classifier: &mut Classifier,
     diff: &git2::Diff,
     commit_id: &str,
     parent_id: Option<&str>,
@@ -82,18 +97,41 @@ fn insert_diff_files(
 
         let is_binary = delta.old_file().is_binary() || delta.new_file().is_binary();
 
+        // Each side is read once; sizes look through LFS pointers
+        let old_id = delta.old_file().id();
+        let new_id = delta.new_file().id();
+        let old_blob = (!old_id.is_zero()).then(|| inspect_blob(odb, old_id)).flatten();
+        let new_blob = (!new_id.is_zero()).then(|| inspect_blob(odb, new_id)).flatten();
+        let is_lfs = [&old_blob, &new_blob]
+            .into_iter()
+            .flatten()
+            .any(|blob| blob.pointer.is_some());
+        let old_size = old_blob.as_ref().map(|b| b.resolved_size() as i64);
+        let new_size = new_blob.as_ref().map(|b| b.resolved_size() as i64);
+
         // Get line stats for this specific file
         let mut insertions = 0i64;
         let mut deletions = 0i64;
 
-        if let Ok(patch) = git2::Patch::from_diff(diff, delta_idx) {
-            if let Some(patch) = patch {
-                let (_, adds, dels) = patch.line_stats()?;
-                insertions = adds as i64;
-                deletions = dels as i64;
-            }
+        if let Ok(Some(patch)) = git2::Patch::from_diff(diff, delta_idx) {
+            let (_, adds, dels) = patch.line_stats()?;
+            insertions = adds as i64;
+            deletions = dels as i64;
         }
 
+        // Classified by the path and content after the change, or before a deletion
+        let (path, content_id) = match (&new_path, new_id.is_zero()) {
+            (Some(path), false) => (Some(path), new_id),
+            _ => (new_path.as_ref().or(old_path.as_ref()), old_id),
+        };
+        let classification = path.map(|path| {
+            classifier.classify(path, || {
+                odb.read(content_id)
+                    .ok()
+                    .map(|object| object.data().to_vec())
+            })
+        });
+
         // Similarity percentage is not directly available in git2-rs API
         // We'd need to compute it ourselves or skip it
         let similarity: Option<i64> = None;
@@ -108,6 +146,11 @@ fn insert_diff_files(
             deletions,
             if is_binary { 1 } else { 0 },
             similarity,
+            if is_lfs { 1 } else { 0 },
+            old_size,
+            new_size,
+            classification.as_ref().and_then(|c| c.language.as_deref()),
+            classification.as_ref().map(|c| c.category),
             repo_path,
         ))?;
     }
@@ -1,4 +1,5 @@
 use crate::error::Result;
+use crate::git::diff::parent_diffs;
 use crate::git::GitRepo;
 use crate::providers::Provider;
 use rusqlite::Connection;
@@ -25,39 +26,19 @@ impl Provider for DiffsProvider {
         for commit_result in repo.walk_commits()? {
             let commit = commit_result?;
             let commit_id = commit.id().to_string();
-            let tree = commit.tree()?;
 
-            if commit.parent_count() == 0 {
-                // Root commit - diff against empty tree
-                let diff = git_repo.diff_tree_to_tree(None, Some(&tree), None)?;
+            // One row per parent (only the first with --first-parent); a root commit is diffed against the empty tree
+            for (parent_id, diff) in parent_diffs(git_repo, &commit, repo.first_parent())? {
                 let stats = diff.stats()?;
 
                 stmt.execute((
                     &commit_id,
-                    Option::<String>::None,
+                    parent_id.map(|id| id.to_string()),
                     stats.files_changed() as i64,
                     stats.insertions() as i64,
                     stats.deletions() as i64,
                     &repo_path,
                 ))?;
-            } else {
-                // Diff against each parent
-                for parent in commit.parents() {
-                    let parent_id = parent.id().to_string();
-                    let parent_tree = parent.tree()?;
-
-                    let diff = git_repo.diff_tree_to_tree(Some(&parent_tree), Some(&tree), None)?;
-                    let stats = diff.stats()?;
-
-                    stmt.execute((
-                        &commit_id,
-                        Some(&parent_id),
-                        stats.files_changed() as i64,
-                        stats.insertions() as i64,
-                        stats.deletions() as i64,
-                        &repo_path,
-                    ))?;
-                }
             }
         }
 
@@ -0,0 +1,120 @@
+use crate::error::Result;
+use crate::git::GitRepo;
+use crate::providers::Provider;
+use git2::DiffOptions;
+use rusqlite::Connection;
+use std::collections::HashMap;
+
+/// Pairs changed together fewer times than this are left out
+pub const DEFAULT_MIN_COUNT: usize = 2;
+/// Commits touching more files than this (reformats, vendoring) are skipped
+pub const DEFAULT_MAX_FILES: usize = 50;
+
+pub struct FileCouplingProvider {
+    pub min_count: usize,
+    pub max_files: usize,
+}
+
+impl FileCouplingProvider {
+    pub fn new(min_count: Option<usize>, max_files: Option<usize>) -> Self {
+        Self {
+            min_count: min_count.unwrap_or(DEFAULT_MIN_COUNT),
+            max_files: max_files.unwrap_or(DEFAULT_MAX_FILES),
+        }
+    }
+}
+
+impl Provider for FileCouplingProvider {
+    fn table_name(&self) -> &'static str {
+        "file_coupling"
+    }
+
+    fn populate(&self, conn: &Connection, repo: &mut GitRepo) -> Result<()> {
+        let mut stmt = conn.prepare(
+            r#"
+            INSERT INTO file_coupling (
+                path, coupled_path, co_changes, path_changes, coupled_changes,
+                support, confidence, repo
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+            "#,
+        )?;
+
+        let repo_path = repo.path().to_string();
+        let git_repo = repo.inner();
+
+        let mut diff_opts = DiffOptions::new();
+        diff_opts.include_untracked(false);
+
+        let mut paths: Vec<String> = Vec::new();
+        let mut path_ids: HashMap<String, usize> = HashMap::new();
+        let mut changes: Vec<usize> = Vec::new();
+        let mut pairs: HashMap<(usize, usize), usize> = HashMap::new();
+        let mut commit_count = 0usize;
+
+        for commit_result in repo.walk_commits()? {
+            let commit = commit_result?;
+            // A merge repeats changes already counted on the merged branch
+            if commit.parent_count() > 1 {
+                continue;
+            }
+
+            let parent_tree = match commit.parent_count() {
+                0 => None,
+                _ => Some(commit.parent(0)?.tree()?),
+            };
+            let diff = git_repo.diff_tree_to_tree(
+                parent_tree.as_ref(),
+                Some(&commit.tree()?),
+                Some(&mut diff_opts),
+            )?;
+            if diff.deltas().len() == 0 || diff.deltas().len() > self.max_files {
+                continue;
+            }
+            commit_count += 1;
+
+            let mut touched: Vec<usize> = diff
+                .deltas()
+                .filter_map(|delta| delta.new_file().path().or_else(|| delta.old_file().path()))
+                .map(|path| {
+                    let path = path.to_string_lossy().to_string();
+                    *path_ids.entry(path.clone()).or_insert_with(|| {
+                        paths.push(path);
+                        changes.push(0);
+                        paths.len() - 1
+                    })
+                })
+                .collect();
+            touched.sort_unstable();
+            touched.dedup();
+
+            for (i, &a) in touched.iter().enumerate() {
+                changes[a] += 1;
+                for &b in &touched[i + 1..] {
+                    *pairs.entry((a, b)).or_insert(0) += 1;
+                }
+            }
+        }
+
+        for (&(a, b), &count) in &pairs {
+            if count < self.min_count {
+                continue;
+            }
+            let support = count as f64 / commit_count as f64;
+            // Confidence is directional: how often changing one path also changed the other
+            for (path, coupled) in [(a, b), (b, a)] {
+                stmt.execute((
+                    &paths[path],
+                    &paths[coupled],
+                    count as i64,
+                    changes[path] as i64,
+                    changes[coupled] as i64,
+                    support,
+                    count as f64 / changes[path] as f64,
+                    &repo_path,
+                ))?;
+            }
+        }
+
+        Ok(())
+    }
+}
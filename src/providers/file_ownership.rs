@@ -0,0 +1,122 @@
+use crate::error::Result;
+use crate::git::blame::{blame_lines, head_paths};
+use crate::git::GitRepo;
+use crate::providers::Provider;
+use rusqlite::Connection;
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+pub struct FileOwnershipProvider;
+
+/// Surviving lines per author email, with the name seen for that email.
+type Owners = HashMap<String, (String, i64)>;
+
+impl Provider for FileOwnershipProvider {
+    fn table_name(&self) -> &'static str {
+        "file_ownership"
+    }
+
+    fn populate(&self, conn: &Connection, repo: &mut GitRepo) -> Result<()> {
+        let mut stmt = conn.prepare(
+            r#"
+            INSERT INTO file_ownership (
+                path, kind, author_name, author_email, lines, total_lines,
+                share, is_primary, bus_factor, repo
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+            "#,
+        )?;
+
+        let repo_path = repo.path().to_string();
+        let git_repo = repo.inner();
+        let head_tree = git_repo.head().ok().and_then(|h| h.peel_to_tree().ok());
+
+        let mut files: BTreeMap<String, Owners> = BTreeMap::new();
+        let mut dirs: BTreeMap<String, Owners> = BTreeMap::new();
+
+        for path in head_paths(git_repo)? {
+            // Lines of a binary file say nothing about who knows it
+            let is_binary = head_tree
+                .as_ref()
+                .and_then(|tree| tree.get_path(Path::new(&path)).ok())
+                .and_then(|entry| git_repo.find_blob(entry.id()).ok())
+                .is_none_or(|blob| blob.is_binary());
+            if is_binary {
+                continue;
+            }
+            let Some(lines) = blame_lines(git_repo, &path, None, true) else {
+                continue;
+            };
+
+            let mut owners = Owners::new();
+            for line in lines {
+                let owner = owners
+                    .entry(line.author_email)
+                    .or_insert_with(|| (line.author_name, 0));
+                owner.1 += 1;
+            }
+
+            // Every enclosing directory, up to "." for the repository root
+            let mut dir = Path::new(&path).parent();
+            while let Some(d) = dir {
+                let name = if d.as_os_str().is_empty() {
+                    ".".to_string()
+                } else {
+                    d.to_string_lossy().to_string()
+                };
+                let totals = dirs.entry(name).or_default();
+                for (email, (author, count)) in &owners {
+                    totals
+                        .entry(email.clone())
+                        .or_insert_with(|| (author.clone(), 0))
+                        .1 += count;
+                }
+                dir = d.parent();
+            }
+            files.insert(path, owners);
+        }
+
+        for (kind, entries) in [("file", &files), ("directory", &dirs)] {
+            for (path, owners) in entries {
+                let total: i64 = owners.values().map(|(_, count)| count).sum();
+                if total == 0 {
+                    continue;
+                }
+
+                // Most lines first; ties broken by email so the primary owner is stable
+                let mut ranked: Vec<(&String, &String, i64)> = owners
+                    .iter()
+                    .map(|(email, (name, count))| (email, name, *count))
+                    .collect();
+                ranked.sort_by(|a, b| b.2.cmp(&a.2).then_with(|| a.0.cmp(b.0)));
+
+                // Fewest authors who together hold at least half the lines
+                let mut covered = 0;
+                let mut bus_factor = 0i64;
+                for (_, _, count) in &ranked {
+                    covered += count;
+                    bus_factor += 1;
+                    if covered * 2 >= total {
+                        break;
+                    }
+                }
+
+                for (i, (email, name, count)) in ranked.iter().enumerate() {
+                    stmt.execute((
+                        path,
+                        kind,
+                        name,
+                        email,
+                        count,
+                        total,
+                        *count as f64 / total as f64,
+                        if i == 0 { 1 } else { 0 },
+                        bus_factor,
+                        &repo_path,
+                    ))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,168 @@
+use crate::error::Result;
+use crate::git::lfs::inspect_blob;
+use crate::git::GitRepo;
+use crate::providers::Provider;
+use chrono::{TimeZone, Utc};
+use git2::{Delta, DiffFindOptions, DiffOptions, Oid};
+use rusqlite::Connection;
+use std::collections::{HashMap, HashSet};
+
+pub struct FileStatsProvider;
+
+#[derive(Default)]
+struct PathStats {
+    commits: i64,
+    authors: HashSet<String>,
+    insertions: i64,
+    deletions: i64,
+    /// (commit time, commit id) of the oldest and newest change
+    first: Option<(i64, Oid)>,
+    last: Option<(i64, Oid)>,
+}
+
+impl Provider for FileStatsProvider {
+    fn table_name(&self) -> &'static str {
+        "file_stats"
+    }
+
+    fn populate(&self, conn: &Connection, repo: &mut GitRepo) -> Result<()> {
+        let mut stmt = conn.prepare(
+            r#"
+            INSERT INTO file_stats (
+                path, commits, authors, insertions, deletions,
+                first_commit_id, first_changed_at, last_commit_id, last_changed_at,
+                size, line_count, repo
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)
+            "#,
+        )?;
+
+        let repo_path = repo.path().to_string();
+        let git_repo = repo.inner();
+
+        let mut diff_opts = DiffOptions::new();
+        diff_opts.include_untracked(false);
+        let mut find_opts = DiffFindOptions::new();
+        find_opts.renames(true);
+
+        let mut stats: HashMap<String, PathStats> = HashMap::new();
+        // Older name -> name the file is reported under. Walking newest first,
+        // a rename is seen before any change made under the older name.
+        let mut renamed_to: HashMap<String, String> = HashMap::new();
+
+        for commit_result in repo.walk_commits()? {
+            let commit = commit_result?;
+            // A merge repeats changes already counted on the merged branch
+            if commit.parent_count() > 1 {
+                continue;
+            }
+
+            let parent_tree = match commit.parent_count() {
+                0 => None,
+                _ => Some(commit.parent(0)?.tree()?),
+            };
+            let mut diff = git_repo.diff_tree_to_tree(
+                parent_tree.as_ref(),
+                Some(&commit.tree()?),
+                Some(&mut diff_opts),
+            )?;
+            diff.find_similar(Some(&mut find_opts))?;
+
+            let author = commit.author().email().unwrap_or("").to_string();
+            let time = commit.time().seconds();
+
+            for (delta_idx, delta) in diff.deltas().enumerate() {
+                let Some(path) = delta
+                    .new_file()
+                    .path()
+                    .or_else(|| delta.old_file().path())
+                    .map(|p| p.to_string_lossy().to_string())
+                else {
+                    continue;
+                };
+                let current = renamed_to.get(&path).cloned().unwrap_or(path);
+
+                if delta.status() == Delta::Renamed {
+                    if let Some(old_path) = delta.old_file().path() {
+                        renamed_to.insert(old_path.to_string_lossy().to_string(), current.clone());
+                    }
+                }
+
+                let (insertions, deletions) = match git2::Patch::from_diff(&diff, delta_idx) {
+                    Ok(Some(patch)) => {
+                        let (_, adds, dels) = patch.line_stats()?;
+                        (adds as i64, dels as i64)
+                    }
+                    _ => (0, 0),
+                };
+
+                let entry = stats.entry(current).or_default();
+                entry.commits += 1;
+                entry.authors.insert(author.clone());
+                entry.insertions += insertions;
+                entry.deletions += deletions;
+                if entry.first.is_none_or(|(t, _)| time <= t) {
+                    entry.first = Some((time, commit.id()));
+                }
+                if entry.last.is_none_or(|(t, _)| time > t) {
+                    entry.last = Some((time, commit.id()));
+                }
+            }
+        }
+
+        let head_tree = git_repo.head().ok().and_then(|h| h.peel_to_tree().ok());
+        let odb = git_repo.odb()?;
+
+        for (path, entry) in &stats {
+            // Size and line count only for files still present at HEAD
+            let blob_id = head_tree
+                .as_ref()
+                .and_then(|tree| tree.get_path(std::path::Path::new(path)).ok())
+                .filter(|e| e.kind() == Some(git2::ObjectType::Blob))
+                .map(|e| e.id());
+            let blob_info = blob_id.and_then(|id| inspect_blob(&odb, id));
+            let size = blob_info.as_ref().map(|info| info.resolved_size() as i64);
+            let is_pointer = blob_info
+                .as_ref()
+                .is_some_and(|info| info.pointer.is_some());
+            let line_count = blob_id
+                .filter(|_| !is_pointer)
+                .and_then(|id| git_repo.find_blob(id).ok())
+                .filter(|blob| !blob.is_binary())
+                .map(|blob| count_lines(blob.content()));
+
+            stmt.execute((
+                path,
+                entry.commits,
+                entry.authors.len() as i64,
+                entry.insertions,
+                entry.deletions,
+                entry.first.map(|(_, id)| id.to_string()),
+                entry.first.map(|(t, _)| format_time(t)),
+                entry.last.map(|(_, id)| id.to_string()),
+                entry.last.map(|(t, _)| format_time(t)),
+                size,
+                line_count,
+                &repo_path,
+            ))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Lines as git counts them: a final line without a newline still counts.
+fn count_lines(content: &[u8]) -> i64 {
+    let newlines = content.iter().filter(|&&b| b == b'\n').count() as i64;
+    match content.last() {
+        Some(b'\n') | None => newlines,
+        Some(_) => newlines + 1,
+    }
+}
+
+fn format_time(timestamp: i64) -> String {
+    if let Some(dt) = Utc.timestamp_opt(timestamp, 0).single() {
+        dt.format("%Y-%m-%d %H:%M:%S").to_string()
+    } else {
+        timestamp.to_string()
+    }
+}
@@ -0,0 +1,50 @@
+use crate::error::Result;
+use crate::git::attributes::{attributes_files, parse_attributes_line};
+use crate::git::GitRepo;
+use crate::providers::Provider;
+use rusqlite::Connection;
+
+pub struct GitattributesProvider;
+
+impl Provider for GitattributesProvider {
+    fn table_name(&self) -> &'static str {
+        "gitattributes"
+    }
+
+    fn populate(&self, conn: &Connection, repo: &mut GitRepo) -> Result<()> {
+        let mut stmt = conn.prepare(
+            r#"
+            INSERT INTO gitattributes (
+                source, scope, line_number, pattern, attribute, value, repo
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+            "#,
+        )?;
+
+        let repo_path = repo.path().to_string();
+        let git_repo = repo.inner();
+
+        let head_tree = git_repo.head().and_then(|h| h.peel_to_tree()).ok();
+        let files = attributes_files(git_repo, head_tree.as_ref())?;
+
+        for file in &files {
+            for (line_idx, line) in file.contents.lines().enumerate() {
+                let Some((pattern, attributes)) = parse_attributes_line(line) else {
+                    continue;
+                };
+                for (attribute, value) in attributes {
+                    stmt.execute((
+                        &file.source,
+                        file.scope,
+                        (line_idx + 1) as i64,
+                        &pattern,
+                        &attribute,
+                        &value,
+                        &repo_path,
+                    ))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
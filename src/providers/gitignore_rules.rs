@@ -0,0 +1,40 @@
+use crate::error::Result;
+use crate::git::ignore::load_ignore_rules;
+use crate::git::GitRepo;
+use crate::providers::Provider;
+use rusqlite::Connection;
+
+pub struct GitignoreRulesProvider;
+
+impl Provider for GitignoreRulesProvider {
+    fn table_name(&self) -> &'static str {
+        "gitignore_rules"
+    }
+
+    fn populate(&self, conn: &Connection, repo: &mut GitRepo) -> Result<()> {
+        let mut stmt = conn.prepare(
+            r#"
+            INSERT INTO gitignore_rules (
+                source, scope, line_number, pattern, is_negated, is_dir_only, precedence, repo
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+            "#,
+        )?;
+
+        let repo_path = repo.path().to_string();
+
+        for (precedence, rule) in load_ignore_rules(repo.inner()).iter().enumerate() {
+            stmt.execute((
+                &rule.source,
+                rule.scope,
+                rule.line_number as i64,
+                &rule.text,
+                if rule.pattern.negated { 1 } else { 0 },
+                if rule.pattern.dir_only { 1 } else { 0 },
+                precedence as i64,
+                &repo_path,
+            ))?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+use crate::error::Result;
+use crate::git::lfs::{lfs_object_path, read_pointer};
+use crate::git::{merged_from_side, GitRepo};
+use crate::providers::Provider;
+use git2::{Delta, Oid};
+use rusqlite::Connection;
+use std::collections::HashSet;
+
+pub struct LfsObjectsProvider;
+
+impl Provider for LfsObjectsProvider {
+    fn table_name(&self) -> &'static str {
+        "lfs_objects"
+    }
+
+    fn populate(&self, conn: &Connection, repo: &mut GitRepo) -> Result<()> {
+        let mut stmt = conn.prepare(
+            r#"
+            INSERT INTO lfs_objects (
+                commit_id, path, status, pointer_id, lfs_oid, size,
+                is_present, in_head, repo
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+            "#,
+        )?;
+
+        let repo_path = repo.path().to_string();
+        let common_dir = repo.common_dir();
+        let git_repo = repo.inner();
+        // Side branches aren't walked then, so merges report what they brought in
+        let first_parent = repo.first_parent();
+        let odb = git_repo.odb()?;
+
+        // (path, blob) pairs for pointers in the HEAD tree
+        let mut head_pointers: HashSet<(String, Oid)> = HashSet::new();
+        if let Ok(tree) = git_repo.head().and_then(|h| h.peel_to_tree()) {
+            tree.walk(git2::TreeWalkMode::PreOrder, |dir, entry| {
+                if entry.kind() == Some(git2::ObjectType::Blob)
+                    && read_pointer(&odb, entry.id()).is_some()
+                {
+                    let path = format!("{}{}", dir, entry.name().unwrap_or(""));
+                    head_pointers.insert((path, entry.id()));
+                }
+                git2::TreeWalkResult::Ok
+            })?;
+        }
+
+        for commit_result in repo.walk_commits()? {
+            let commit = commit_result?;
+            let commit_id = commit.id().to_string();
+            let tree = commit.tree()?;
+
+            // Compare with the first parent so each pointer is attributed to the
+            // commit that introduced it; merges only report what no parent had
+            let parent_tree = match commit.parent(0) {
+                Ok(parent) => Some(parent.tree()?),
+                Err(_) => None,
+            };
+            let diff = git_repo.diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), None)?;
+
+            for delta in diff.deltas() {
+                let status = match delta.status() {
+                    Delta::Added => "A",
+                    Delta::Modified => "M",
+                    Delta::Typechange => "T",
+                    _ => continue,
+                };
+
+                let pointer_id = delta.new_file().id();
+                let Some(pointer) = read_pointer(&odb, pointer_id) else {
+                    continue;
+                };
+                let Some(new_path) = delta.new_file().path() else {
+                    continue;
+                };
+                if !first_parent && merged_from_side(&commit, new_path, Some(pointer_id)) {
+                    continue;
+                }
+                let path = new_path.to_string_lossy().to_string();
+
+                let is_present = lfs_object_path(&common_dir, &pointer.oid).is_file();
+                let in_head = head_pointers.contains(&(path.clone(), pointer_id));
+
+                stmt.execute((
+                    &commit_id,
+                    &path,
+                    status,
+                    pointer_id.to_string(),
+                    &pointer.oid,
+                    pointer.size as i64,
+                    if is_present { 1 } else { 0 },
+                    if in_head { 1 } else { 0 },
+                    &repo_path,
+                ))?;
+            }
+        }
+
+        Ok(())
+    }
+}
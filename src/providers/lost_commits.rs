@@ -0,0 +1,207 @@
+use crate::error::Result;
+use crate::git::reachability::{mark_reachable, ref_roots, reflog_names};
+use crate::git::GitRepo;
+use crate::providers::Provider;
+use chrono::{TimeZone, Utc};
+use git2::{ObjectType, Oid, Repository};
+use rusqlite::Connection;
+use std::collections::{HashMap, HashSet};
+
+pub struct LostCommitsProvider;
+
+/// Where an unreachable commit was last seen.
+struct Sighting {
+    source: &'static str,
+    ref_name: Option<String>,
+    seen_at: Option<git2::Time>,
+}
+
+impl Provider for LostCommitsProvider {
+    fn table_name(&self) -> &'static str {
+        "lost_commits"
+    }
+
+    fn populate(&self, conn: &Connection, repo: &mut GitRepo) -> Result<()> {
+        let mut stmt = conn.prepare(
+            r#"
+            INSERT INTO lost_commits (
+                commit_id, short_id, summary, author_name, author_email, authored_at,
+                source, last_ref, last_seen_at, is_tip, repo
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
+            "#,
+        )?;
+
+        let repo_path = repo.path().to_string();
+
+        // Stash entries are only kept alive by refs/stash and its reflog, so they
+        // count as lost work rather than as reachable history
+        let roots: Vec<(String, Oid)> = ref_roots(repo)?
+            .into_iter()
+            .filter(|(name, _)| name != "refs/stash")
+            .collect();
+        let git_repo = repo.inner();
+        let reachable = mark_reachable(git_repo, &roots, true);
+
+        // Every reflog entry, most recent first across all refs
+        let mut reflog_entries: Vec<(String, Oid, git2::Time)> = Vec::new();
+        for name in reflog_names(repo) {
+            if let Ok(reflog) = git_repo.reflog(&name) {
+                for entry in reflog.iter() {
+                    let when = entry.committer().when();
+                    for oid in [entry.id_new(), entry.id_old()] {
+                        if !oid.is_zero() && !reachable.contains_key(&oid) {
+                            reflog_entries.push((name.clone(), oid, when));
+                        }
+                    }
+                }
+            }
+        }
+        reflog_entries.sort_by_key(|(_, _, when)| std::cmp::Reverse(when.seconds()));
+
+        let mut lost: Vec<Oid> = Vec::new();
+        let mut sightings: HashMap<Oid, Sighting> = HashMap::new();
+        // Index and untracked-files commits that `git stash` records as extra
+        // parents; they are part of the stash, not work of their own
+        let mut stash_helpers: HashSet<Oid> = HashSet::new();
+
+        for (name, oid, when) in reflog_entries {
+            let is_stash = name == "refs/stash";
+            if is_stash {
+                if let Ok(commit) = git_repo.find_commit(oid) {
+                    stash_helpers.extend(commit.parent_ids().skip(1));
+                }
+            }
+            let sighting = || Sighting {
+                source: if is_stash { "stash" } else { "reflog" },
+                ref_name: Some(name.clone()),
+                seen_at: Some(when),
+            };
+            walk_lost(
+                git_repo,
+                oid,
+                is_stash,
+                &reachable,
+                &mut lost,
+                &mut sightings,
+                sighting,
+            );
+        }
+
+        // Whatever is left in the object database was never recorded by a reflog
+        let odb = git_repo.odb()?;
+        let mut dangling: Vec<(Oid, i64)> = Vec::new();
+        odb.foreach(|oid| {
+            if !reachable.contains_key(oid)
+                && !sightings.contains_key(oid)
+                && !stash_helpers.contains(oid)
+            {
+                if let Ok((_, ObjectType::Commit)) = odb.read_header(*oid) {
+                    if let Ok(commit) = git_repo.find_commit(*oid) {
+                        dangling.push((*oid, commit.time().seconds()));
+                    }
+                }
+            }
+            true
+        })?;
+        dangling.sort_by_key(|(oid, seconds)| (std::cmp::Reverse(*seconds), *oid));
+        dangling.dedup();
+
+        for (oid, _) in dangling {
+            walk_lost(
+                git_repo,
+                oid,
+                false,
+                &reachable,
+                &mut lost,
+                &mut sightings,
+                || Sighting {
+                    source: "dangling",
+                    ref_name: None,
+                    seen_at: None,
+                },
+            );
+        }
+
+        // A tip is a lost commit that no other lost commit builds on
+        let mut lost_parents: HashSet<Oid> = HashSet::new();
+        for oid in &lost {
+            if let Ok(commit) = git_repo.find_commit(*oid) {
+                lost_parents.extend(commit.parent_ids());
+            }
+        }
+
+        for oid in lost {
+            let Ok(commit) = git_repo.find_commit(oid) else {
+                continue;
+            };
+            let Some(sighting) = sightings.get(&oid) else {
+                continue;
+            };
+
+            let id = oid.to_string();
+            let short_id = &id[..7.min(id.len())];
+            let summary = commit.summary().unwrap_or("").to_string();
+            let author = commit.author();
+            let author_name = author.name().unwrap_or("").to_string();
+            let author_email = author.email().unwrap_or("").to_string();
+            let authored_at = format_git_time(author.when());
+            let last_seen_at = sighting.seen_at.map(format_git_time);
+            let is_tip = !lost_parents.contains(&oid);
+
+            stmt.execute((
+                &id,
+                short_id,
+                &summary,
+                &author_name,
+                &author_email,
+                &authored_at,
+                sighting.source,
+                &sighting.ref_name,
+                &last_seen_at,
+                if is_tip { 1 } else { 0 },
+                &repo_path,
+            ))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Record `start` and its unreachable ancestors, attributing each commit to
+/// the first sighting that reaches it. With `first_parent` only the first
+/// parent of each commit is followed.
+fn walk_lost(
+    repo: &Repository,
+    start: Oid,
+    first_parent: bool,
+    reachable: &HashMap<Oid, String>,
+    lost: &mut Vec<Oid>,
+    sightings: &mut HashMap<Oid, Sighting>,
+    sighting: impl Fn() -> Sighting,
+) {
+    let mut stack = vec![start];
+    while let Some(oid) = stack.pop() {
+        if reachable.contains_key(&oid) || sightings.contains_key(&oid) {
+            continue;
+        }
+        let Ok(commit) = repo.find_commit(oid) else {
+            continue;
+        };
+        sightings.insert(oid, sighting());
+        lost.push(oid);
+        if first_parent {
+            stack.extend(commit.parent_id(0).ok());
+        } else {
+            stack.extend(commit.parent_ids());
+        }
+    }
+}
+
+fn format_git_time(time: git2::Time) -> String {
+    let timestamp = time.seconds();
+    if let Some(dt) = Utc.timestamp_opt(timestamp, 0).single() {
+        dt.format("%Y-%m-%d %H:%M:%S").to_string()
+    } else {
+        timestamp.to_string()
+    }
+}
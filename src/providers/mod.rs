@@ -1,37 +1,81 @@
 mod blame;
+mod branch_report;
 mod branches;
+mod codeowners;
+mod commit_branches;
+mod commit_graph;
 mod commit_parents;
+mod commit_references;
+mod commit_tags;
 mod commits;
 mod config;
 mod diff_files;
 mod diffs;
+mod file_coupling;
+mod file_ownership;
+mod file_stats;
+mod gitattributes;
+mod gitignore_rules;
 mod hooks;
+mod lfs_objects;
+mod lost_commits;
 mod notes;
+mod objects;
+mod odb_stats;
+mod packfiles;
+mod patch_ids;
 mod reflog;
 mod refs;
 mod remotes;
+mod replace_refs;
+mod reverts;
 mod stashes;
+mod shallow;
 mod status;
+mod submodule_updates;
 mod submodules;
 mod tags;
+mod todos;
 mod worktrees;
 
 pub use blame::BlameProvider;
+pub use branch_report::BranchReportProvider;
 pub use branches::BranchesProvider;
+pub use codeowners::CodeownersProvider;
+pub use commit_branches::CommitBranchesProvider;
+pub use commit_graph::CommitGraphProvider;
 pub use commit_parents::CommitParentsProvider;
+pub use commit_references::CommitReferencesProvider;
+pub use commit_tags::CommitTagsProvider;
 pub use commits::CommitsProvider;
 pub use config::ConfigProvider;
 pub use diff_files::DiffFilesProvider;
 pub use diffs::DiffsProvider;
+pub use file_coupling::FileCouplingProvider;
+pub use file_ownership::FileOwnershipProvider;
+pub use file_stats::FileStatsProvider;
+pub use gitattributes::GitattributesProvider;
+pub use gitignore_rules::GitignoreRulesProvider;
 pub use hooks::HooksProvider;
+pub use lfs_objects::LfsObjectsProvider;
+pub use lost_commits::LostCommitsProvider;
 pub use notes::NotesProvider;
+pub use objects::ObjectsProvider;
+pub use odb_stats::OdbStatsProvider;
+pub use packfiles::PackfilesProvider;
+pub use patch_ids::PatchIdsProvider;
 pub use reflog::ReflogProvider;
 pub use refs::RefsProvider;
 pub use remotes::RemotesProvider;
+pub use replace_refs::ReplaceRefsProvider;
+pub use reverts::RevertsProvider;
 pub use stashes::StashesProvider;
+pub use shallow::ShallowProvider;
 pub use status::StatusProvider;
+pub use submodule_updates::SubmoduleUpdatesProvider;
 pub use submodules::SubmodulesProvider;
 pub use tags::TagsProvider;
+pub use todos::TodosProvider;
 pub use worktrees::WorktreesProvider;
 
 use crate::error::Result;
@@ -0,0 +1,72 @@
+use crate::error::Result;
+use crate::git::reachability::{mark_reachable, reflog_roots, ref_roots};
+use crate::git::GitRepo;
+use crate::providers::Provider;
+use git2::{ObjectType, Oid};
+use rusqlite::Connection;
+use std::collections::HashSet;
+
+pub struct ObjectsProvider;
+
+impl Provider for ObjectsProvider {
+    fn table_name(&self) -> &'static str {
+        "objects"
+    }
+
+    fn populate(&self, conn: &Connection, repo: &mut GitRepo) -> Result<()> {
+        let mut stmt = conn.prepare(
+            r#"
+            INSERT INTO objects (
+                id, type, size, is_reachable, reachable_from, repo
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+            "#,
+        )?;
+
+        let repo_path = repo.path().to_string();
+
+        // Refs claim objects before reflogs so reachable_from names a live ref when possible
+        let mut roots = ref_roots(repo)?;
+        roots.extend(reflog_roots(repo)?);
+        let reached = mark_reachable(repo.inner(), &roots, false);
+
+        let git_repo = repo.inner();
+        let odb = git_repo.odb()?;
+
+        // The same object may be stored both loose and packed
+        let mut seen: HashSet<Oid> = HashSet::new();
+        odb.foreach(|oid| {
+            seen.insert(*oid);
+            true
+        })?;
+
+        let mut ids: Vec<Oid> = seen.into_iter().collect();
+        ids.sort();
+
+        for oid in ids {
+            let Ok((size, kind)) = odb.read_header(oid) else {
+                continue;
+            };
+
+            let object_type = match kind {
+                ObjectType::Commit => "commit",
+                ObjectType::Tree => "tree",
+                ObjectType::Blob => "blob",
+                ObjectType::Tag => "tag",
+                _ => "unknown",
+            };
+
+            let reachable_from = reached.get(&oid);
+
+            stmt.execute((
+                oid.to_string(),
+                object_type,
+                size as i64,
+                if reachable_from.is_some() { 1 } else { 0 },
+                reachable_from,
+                &repo_path,
+            ))?;
+        }
+
+        Ok(())
+    }
+}
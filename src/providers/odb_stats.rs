@@ -0,0 +1,73 @@
+use crate::error::Result;
+use crate::git::pack::{read_pack_index_ids, scan_loose_objects, scan_packs};
+use crate::git::GitRepo;
+use crate::providers::Provider;
+use rusqlite::Connection;
+
+pub struct OdbStatsProvider;
+
+impl Provider for OdbStatsProvider {
+    fn table_name(&self) -> &'static str {
+        "odb_stats"
+    }
+
+    fn populate(&self, conn: &Connection, repo: &mut GitRepo) -> Result<()> {
+        let mut stmt = conn.prepare(
+            r#"
+            INSERT INTO odb_stats (
+                loose_count, loose_size, packed_count, pack_count, packed_size,
+                prune_packable, garbage_count, garbage_size, repo
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+            "#,
+        )?;
+
+        let repo_path = repo.path().to_string();
+        let objects_dir = repo.objects_dir();
+
+        let (packs, pack_garbage) = scan_packs(&objects_dir)?;
+        let (loose, loose_garbage) = scan_loose_objects(&objects_dir)?;
+
+        let loose_size: u64 = loose.iter().map(|o| o.size).sum();
+        let packed_count: u64 = packs.iter().filter_map(|p| p.object_count).map(u64::from).sum();
+        let packed_size: u64 = packs.iter().map(|p| p.size).sum();
+
+        // Loose objects that already live in a pack are what `git prune-packed` removes
+        let mut prune_packable = 0i64;
+        if !loose.is_empty() {
+            let mut indexes = Vec::new();
+            for pack in packs.iter().filter(|p| p.has_idx) {
+                indexes.push(read_pack_index_ids(&pack.idx_path())?);
+            }
+            for object in &loose {
+                if let Ok(oid) = git2::Oid::from_str(&object.id) {
+                    let mut raw = [0u8; 20];
+                    raw.copy_from_slice(oid.as_bytes());
+                    if indexes.iter().any(|ids| ids.binary_search(&raw).is_ok()) {
+                        prune_packable += 1;
+                    }
+                }
+            }
+        }
+
+        let garbage_count = pack_garbage.len() + loose_garbage.len();
+        let garbage_size: u64 = pack_garbage
+            .iter()
+            .chain(loose_garbage.iter())
+            .map(|g| g.size)
+            .sum();
+
+        stmt.execute((
+            loose.len() as i64,
+            loose_size as i64,
+            packed_count as i64,
+            packs.len() as i64,
+            packed_size as i64,
+            prune_packable,
+            garbage_count as i64,
+            garbage_size as i64,
+            &repo_path,
+        ))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use crate::error::Result;
+use crate::git::pack::scan_packs;
+use crate::git::GitRepo;
+use crate::providers::Provider;
+use chrono::{DateTime, Utc};
+use rusqlite::Connection;
+use std::time::SystemTime;
+
+pub struct PackfilesProvider;
+
+impl Provider for PackfilesProvider {
+    fn table_name(&self) -> &'static str {
+        "packfiles"
+    }
+
+    fn populate(&self, conn: &Connection, repo: &mut GitRepo) -> Result<()> {
+        let mut stmt = conn.prepare(
+            r#"
+            INSERT INTO packfiles (
+                name, path, size, object_count, has_idx, has_bitmap,
+                has_rev, has_keep, modified_at, age_days, repo
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
+            "#,
+        )?;
+
+        let repo_path = repo.path().to_string();
+        let (packs, _) = scan_packs(&repo.objects_dir())?;
+        let now = SystemTime::now();
+
+        for pack in packs {
+            let modified_at = pack
+                .modified
+                .map(|t| DateTime::<Utc>::from(t).format("%Y-%m-%d %H:%M:%S").to_string());
+            let age_days = pack
+                .modified
+                .and_then(|t| now.duration_since(t).ok())
+                .map(|d| (d.as_secs() / 86_400) as i64);
+
+            stmt.execute((
+                &pack.name,
+                pack.path.to_string_lossy().to_string(),
+                pack.size as i64,
+                pack.object_count.map(|c| c as i64),
+                if pack.has_idx { 1 } else { 0 },
+                if pack.has_bitmap { 1 } else { 0 },
+                if pack.has_rev { 1 } else { 0 },
+                if pack.has_keep { 1 } else { 0 },
+                &modified_at,
+                age_days,
+                &repo_path,
+            ))?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+use crate::error::Result;
+use crate::git::patch_id::commit_patch_id;
+use crate::git::GitRepo;
+use crate::providers::Provider;
+use rusqlite::Connection;
+
+pub struct PatchIdsProvider;
+
+impl Provider for PatchIdsProvider {
+    fn table_name(&self) -> &'static str {
+        "patch_ids"
+    }
+
+    fn populate(&self, conn: &Connection, repo: &mut GitRepo) -> Result<()> {
+        let mut stmt = conn.prepare(
+            r#"
+            INSERT INTO patch_ids (
+                commit_id, patch_id, repo
+            ) VALUES (?1, ?2, ?3)
+            "#,
+        )?;
+
+        let repo_path = repo.path().to_string();
+        let git_repo = repo.inner();
+
+        // Every branch, not just HEAD, so copies on other branches can be paired up
+        let mut revwalk = git_repo.revwalk()?;
+        revwalk.set_sorting(git2::Sort::TIME | git2::Sort::TOPOLOGICAL)?;
+        revwalk.push_glob("refs/heads")?;
+        revwalk.push_glob("refs/remotes")?;
+        if git_repo.head().is_ok() {
+            revwalk.push_head()?;
+        }
+        if repo.first_parent() {
+            revwalk.simplify_first_parent()?;
+        }
+
+        for oid in revwalk {
+            let commit = git_repo.find_commit(oid?)?;
+            // Hashed from the same per-parent diff the diffs table counts;
+            // merges and empty commits have no patch-id
+            if let Some(patch_id) = commit_patch_id(git_repo, &commit)? {
+                stmt.execute((commit.id().to_string(), patch_id.to_string(), &repo_path))?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -55,36 +55,34 @@ impl Provider for ReflogProvider {
         }
 
         // Get reflog for all branches
-        for reference_result in git_repo.references()? {
-            if let Ok(reference) = reference_result {
-                if let Some(name) = reference.name() {
-                    if name.starts_with("refs/heads/") {
-                        if let Ok(reflog) = git_repo.reflog(name) {
-                            for (index, entry) in reflog.iter().enumerate() {
-                                let old_id = entry.id_old().to_string();
-                                let new_id = entry.id_new().to_string();
+        for reference in git_repo.references()?.flatten() {
+            if let Some(name) = reference.name() {
+                if name.starts_with("refs/heads/") {
+                    if let Ok(reflog) = git_repo.reflog(name) {
+                        for (index, entry) in reflog.iter().enumerate() {
+                            let old_id = entry.id_old().to_string();
+                            let new_id = entry.id_new().to_string();
 
-                                let committer = entry.committer();
-                                let committer_name = committer.name().unwrap_or("").to_string();
-                                let committer_email = committer.email().unwrap_or("").to_string();
-                                let committed_at = format_git_time(committer.when());
+                            let committer = entry.committer();
+                            let committer_name = committer.name().unwrap_or("").to_string();
+                            let committer_email = committer.email().unwrap_or("").to_string();
+                            let committed_at = format_git_time(committer.when());
 
-                                let message = entry.message().unwrap_or("").to_string();
-                                let action = extract_action(&message);
+                            let message = entry.message().unwrap_or("").to_string();
+                            let action = extract_action(&message);
 
-                                stmt.execute((
-                                    name,
-                                    index as i64,
-                                    &old_id,
-                                    &new_id,
-                                    &committer_name,
-                                    &committer_email,
-                                    &committed_at,
-                                    &message,
-                                    &action,
-                                    &repo_path,
-                                ))?;
-                            }
+                            stmt.execute((
+                                name,
+                                index as i64,
+                                &old_id,
+                                &new_id,
+                                &committer_name,
+                                &committer_email,
+                                &committed_at,
+                                &message,
+                                &action,
+                                &repo_path,
+                            ))?;
                         }
                     }
                 }
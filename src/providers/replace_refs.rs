@@ -0,0 +1,69 @@
+use crate::error::Result;
+use crate::git::GitRepo;
+use crate::providers::Provider;
+use rusqlite::Connection;
+
+pub struct ReplaceRefsProvider;
+
+impl Provider for ReplaceRefsProvider {
+    fn table_name(&self) -> &'static str {
+        "replace_refs"
+    }
+
+    fn populate(&self, conn: &Connection, repo: &mut GitRepo) -> Result<()> {
+        let mut stmt = conn.prepare(
+            r#"
+            INSERT INTO replace_refs (
+                original_id, replacement_id, original_type, replacement_type, repo
+            ) VALUES (?1, ?2, ?3, ?4, ?5)
+            "#,
+        )?;
+
+        let repo_path = repo.path().to_string();
+        let git_repo = repo.inner();
+
+        // libgit2 does not apply replacements, so every other table shows the
+        // objects as stored; this table lets queries apply them explicitly
+        for reference in git_repo.references_glob("refs/replace/*")?.flatten() {
+            let Some(name) = reference.name() else {
+                continue;
+            };
+            let original_id = name.trim_start_matches("refs/replace/").to_string();
+            let Some(replacement) = reference.target() else {
+                continue;
+            };
+
+            let original_type = git2::Oid::from_str(&original_id)
+                .ok()
+                .and_then(|oid| git_repo.find_object(oid, None).ok())
+                .and_then(|obj| obj.kind())
+                .map(object_type_name);
+            let replacement_type = git_repo
+                .find_object(replacement, None)
+                .ok()
+                .and_then(|obj| obj.kind())
+                .map(object_type_name);
+
+            stmt.execute((
+                &original_id,
+                replacement.to_string(),
+                &original_type,
+                &replacement_type,
+                &repo_path,
+            ))?;
+        }
+
+        Ok(())
+    }
+}
+
+fn object_type_name(kind: git2::ObjectType) -> String {
+    match kind {
+        git2::ObjectType::Commit => "commit",
+        git2::ObjectType::Tree => "tree",
+        git2::ObjectType::Blob => "blob",
+        git2::ObjectType::Tag => "tag",
+        _ => "unknown",
+    }
+    .to_string()
+}
@@ -0,0 +1,86 @@
+use crate::error::Result;
+use crate::git::patch_id::{commit_patch_id, inverse_patch_id};
+use crate::git::GitRepo;
+use crate::providers::Provider;
+use git2::Oid;
+use regex::Regex;
+use rusqlite::Connection;
+use std::collections::HashMap;
+
+pub struct RevertsProvider;
+
+impl Provider for RevertsProvider {
+    fn table_name(&self) -> &'static str {
+        "reverts"
+    }
+
+    fn populate(&self, conn: &Connection, repo: &mut GitRepo) -> Result<()> {
+        let mut stmt = conn.prepare(
+            r#"
+            INSERT INTO reverts (
+                commit_id, reverted_id, detected_by, patch_matches, depth, repo
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+            "#,
+        )?;
+
+        let repo_path = repo.path().to_string();
+        let git_repo = repo.inner();
+        let message_re = Regex::new(r"This reverts commit ([0-9a-fA-F]{7,40})").unwrap();
+
+        let mut history = Vec::new();
+        for commit_result in repo.walk_commits()? {
+            history.push(commit_result?.id());
+        }
+
+        // Oldest first, so whatever a commit reverts has already been seen
+        let mut undone_by: HashMap<Oid, Oid> = HashMap::new();
+        let mut inverse_ids: HashMap<Oid, Option<Oid>> = HashMap::new();
+        let mut depths: HashMap<Oid, i64> = HashMap::new();
+
+        for oid in history.into_iter().rev() {
+            let commit = git_repo.find_commit(oid)?;
+            let patch_id = commit_patch_id(git_repo, &commit)?;
+
+            let named = message_re
+                .captures(commit.message().unwrap_or(""))
+                .and_then(|caps| git_repo.revparse_single(&caps[1]).ok())
+                .and_then(|object| object.peel_to_commit().ok())
+                .map(|c| c.id());
+
+            let found = match named {
+                Some(target) => {
+                    let inverse = match inverse_ids.get(&target) {
+                        Some(inverse) => *inverse,
+                        None => inverse_patch_id(git_repo, &git_repo.find_commit(target)?)?,
+                    };
+                    let matches = patch_id.is_some() && patch_id == inverse;
+                    Some((target, "message", matches))
+                }
+                None => patch_id
+                    .and_then(|id| undone_by.get(&id))
+                    .map(|target| (*target, "patch-id", true)),
+            };
+
+            if let Some((target, detected_by, matches)) = found {
+                let depth = depths.get(&target).copied().unwrap_or(0) + 1;
+                depths.insert(oid, depth);
+                stmt.execute((
+                    oid.to_string(),
+                    target.to_string(),
+                    detected_by,
+                    if matches { 1 } else { 0 },
+                    depth,
+                    &repo_path,
+                ))?;
+            }
+
+            let inverse = inverse_patch_id(git_repo, &commit)?;
+            if let Some(inverse) = inverse {
+                undone_by.insert(inverse, oid);
+            }
+            inverse_ids.insert(oid, inverse);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+use crate::error::Result;
+use crate::git::GitRepo;
+use crate::providers::Provider;
+use chrono::{TimeZone, Utc};
+use git2::Oid;
+use rusqlite::Connection;
+
+pub struct ShallowProvider;
+
+impl Provider for ShallowProvider {
+    fn table_name(&self) -> &'static str {
+        "shallow"
+    }
+
+    fn populate(&self, conn: &Connection, repo: &mut GitRepo) -> Result<()> {
+        let mut stmt = conn.prepare(
+            r#"
+            INSERT INTO shallow (
+                commit_id, source, summary, committed_at, parent_count, repo
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+            "#,
+        )?;
+
+        let repo_path = repo.path().to_string();
+        let git_repo = repo.inner();
+
+        let mut boundaries: Vec<(Oid, &str)> = Vec::new();
+        boundaries.extend(repo.shallow_commits()?.into_iter().map(|oid| (oid, "shallow")));
+        boundaries.extend(repo.grafted_commits()?.into_iter().map(|oid| (oid, "graft")));
+        boundaries.sort();
+
+        for (oid, source) in boundaries {
+            // The boundary commit itself is present; only its parents are missing
+            let commit = git_repo.find_commit(oid).ok();
+            let summary = commit
+                .as_ref()
+                .and_then(|c| c.summary().map(|s| s.to_string()));
+            let committed_at = commit.as_ref().map(|c| format_git_time(c.committer().when()));
+            let parent_count = commit.as_ref().map(|c| c.parent_count() as i64);
+
+            stmt.execute((
+                oid.to_string(),
+                source,
+                &summary,
+                &committed_at,
+                parent_count,
+                &repo_path,
+            ))?;
+        }
+
+        Ok(())
+    }
+}
+
+fn format_git_time(time: git2::Time) -> String {
+    let timestamp = time.seconds();
+    if let Some(dt) = Utc.timestamp_opt(timestamp, 0).single() {
+        dt.format("%Y-%m-%d %H:%M:%S").to_string()
+    } else {
+        timestamp.to_string()
+    }
+}
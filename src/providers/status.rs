@@ -4,7 +4,15 @@ use crate::providers::Provider;
 use git2::{Status, StatusOptions};
 use rusqlite::Connection;
 
-pub struct StatusProvider;
+pub struct StatusProvider {
+    pub include_ignored: bool,
+}
+
+impl StatusProvider {
+    pub fn new(include_ignored: bool) -> Self {
+        Self { include_ignored }
+    }
+}
 
 impl Provider for StatusProvider {
     fn table_name(&self) -> &'static str {
@@ -27,7 +35,7 @@ impl Provider for StatusProvider {
 
         let mut opts = StatusOptions::new();
         opts.include_untracked(true)
-            .include_ignored(false)
+            .include_ignored(self.include_ignored)
             .include_unmodified(false)
             .recurse_untracked_dirs(true);
 
@@ -0,0 +1,124 @@
+use crate::error::Result;
+use crate::git::{merged_from_side, GitRepo};
+use crate::providers::Provider;
+use git2::{FileMode, Oid, Repository};
+use rusqlite::Connection;
+use std::collections::HashMap;
+
+pub struct SubmoduleUpdatesProvider;
+
+impl Provider for SubmoduleUpdatesProvider {
+    fn table_name(&self) -> &'static str {
+        "submodule_updates"
+    }
+
+    fn populate(&self, conn: &Connection, repo: &mut GitRepo) -> Result<()> {
+        let mut stmt = conn.prepare(
+            r#"
+            INSERT INTO submodule_updates (
+                commit_id, path, old_id, new_id, change, direction,
+                commits_added, commits_removed, repo
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+            "#,
+        )?;
+
+        let repo_path = repo.path().to_string();
+        let git_repo = repo.inner();
+        // Side branches aren't walked then, so merges report what they brought in
+        let first_parent = repo.first_parent();
+
+        // Submodule repositories by path, opened on first use; None when not checked out
+        let mut submodule_repos: HashMap<String, Option<Repository>> = HashMap::new();
+
+        for commit_result in repo.walk_commits()? {
+            let commit = commit_result?;
+            let commit_id = commit.id().to_string();
+            let tree = commit.tree()?;
+
+            // Against the first parent; a merge only reports pins none of its
+            // merged parents already had
+            let parent_tree = match commit.parent(0) {
+                Ok(parent) => Some(parent.tree()?),
+                Err(_) => None,
+            };
+            let diff = git_repo.diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), None)?;
+
+            for delta in diff.deltas() {
+                let old_file = delta.old_file();
+                let new_file = delta.new_file();
+                let old_is_link = old_file.mode() == FileMode::Commit;
+                let new_is_link = new_file.mode() == FileMode::Commit;
+                if !old_is_link && !new_is_link {
+                    continue;
+                }
+
+                let old_id = old_is_link.then(|| old_file.id());
+                let new_id = new_is_link.then(|| new_file.id());
+                let change = match (old_id, new_id) {
+                    (None, Some(_)) => "added",
+                    (Some(_), None) => "removed",
+                    _ => "updated",
+                };
+
+                let Some(entry_path) = new_file.path().or_else(|| old_file.path()) else {
+                    continue;
+                };
+                if !first_parent && merged_from_side(&commit, entry_path, new_id) {
+                    continue;
+                }
+                let path = entry_path.to_string_lossy().to_string();
+
+                let (direction, commits_added, commits_removed) = match (old_id, new_id) {
+                    (Some(old), Some(new)) => {
+                        let sub_repo = submodule_repos
+                            .entry(path.clone())
+                            .or_insert_with(|| open_submodule(git_repo, &path));
+                        compare_pins(sub_repo.as_ref(), old, new)
+                    }
+                    _ => (None, None, None),
+                };
+
+                stmt.execute((
+                    &commit_id,
+                    &path,
+                    old_id.map(|id| id.to_string()),
+                    new_id.map(|id| id.to_string()),
+                    change,
+                    direction,
+                    commits_added,
+                    commits_removed,
+                    &repo_path,
+                ))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn open_submodule(repo: &Repository, path: &str) -> Option<Repository> {
+    repo.find_submodule(path).ok()?.open().ok()
+}
+
+/// Classify a pin change as fast-forward, rewind or diverged, with the number
+/// of commits gained and dropped. Unknown when the submodule isn't available.
+fn compare_pins(
+    sub_repo: Option<&Repository>,
+    old: Oid,
+    new: Oid,
+) -> (Option<&'static str>, Option<i64>, Option<i64>) {
+    let Some(sub_repo) = sub_repo else {
+        return (None, None, None);
+    };
+    let Ok((ahead, behind)) = sub_repo.graph_ahead_behind(new, old) else {
+        return (None, None, None);
+    };
+
+    let direction = match (ahead, behind) {
+        (_, 0) => "fast-forward",
+        (0, _) => "rewind",
+        _ => "diverged",
+    };
+
+    (Some(direction), Some(ahead as i64), Some(behind as i64))
+}
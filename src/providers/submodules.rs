@@ -14,8 +14,8 @@ impl Provider for SubmodulesProvider {
         let mut stmt = conn.prepare(
             r#"
             INSERT INTO submodules (
-                name, path, url, branch, head_id, status, repo
-            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+                name, path, url, branch, head_id, status, submodule_repo, repo
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
             "#,
         )?;
 
@@ -38,6 +38,12 @@ impl Provider for SubmodulesProvider {
                     (None, Some(_)) => "added",
                 };
 
+                // The `repo` value its own rows get under --recurse-submodules
+                let submodule_repo = submodule
+                    .open()
+                    .ok()
+                    .map(|sub| GitRepo::from_repository(sub).path().to_string());
+
                 stmt.execute((
                     &name,
                     &path,
@@ -45,6 +51,7 @@ impl Provider for SubmodulesProvider {
                     &branch,
                     &head_id,
                     status,
+                    &submodule_repo,
                     &repo_path,
                 ))?;
             }
@@ -0,0 +1,162 @@
+use crate::error::Result;
+use crate::git::blame::blame_lines;
+use crate::git::language::{comments, Classifier};
+use crate::git::lfs::parse_pointer;
+use crate::git::GitRepo;
+use crate::providers::Provider;
+use chrono::{TimeZone, Utc};
+use git2::{ObjectType, TreeWalkMode, TreeWalkResult};
+use regex::Regex;
+use rusqlite::Connection;
+
+/// Markers every repository gets
+const BUILTIN_TAGS: &[&str] = &["TODO", "FIXME", "HACK", "XXX"];
+
+pub struct TodosProvider {
+    /// Extra markers, searched alongside the built-in ones
+    pub tags: Vec<String>,
+    /// Revision to scan; HEAD when unset
+    pub rev: Option<String>,
+}
+
+impl TodosProvider {
+    pub fn new(tags: Vec<String>, rev: Option<String>) -> Self {
+        Self { tags, rev }
+    }
+}
+
+impl Provider for TodosProvider {
+    fn table_name(&self) -> &'static str {
+        "todos"
+    }
+
+    fn populate(&self, conn: &Connection, repo: &mut GitRepo) -> Result<()> {
+        let mut stmt = conn.prepare(
+            r#"
+            INSERT INTO todos (
+                path, line_number, tag, text, assignee, commit_id,
+                author_name, author_email, authored_at, age_days, repo
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
+            "#,
+        )?;
+
+        let repo_path = repo.path().to_string();
+        let git_repo = repo.inner();
+
+        // An unborn HEAD has nothing to scan yet
+        if self.rev.is_none() && git_repo.head().is_err() {
+            return Ok(());
+        }
+        let commit = git_repo
+            .revparse_single(self.rev.as_deref().unwrap_or("HEAD"))?
+            .peel_to_commit()?;
+
+        // Per-repository tags from `git config --add vcsql.todoTag TAG`
+        let mut tags: Vec<String> = BUILTIN_TAGS.iter().map(|t| t.to_string()).collect();
+        if let Ok(config) = git_repo.config() {
+            if let Ok(mut entries) = config.multivar("vcsql.todoTag", None) {
+                while let Some(Ok(entry)) = entries.next() {
+                    if let Some(value) = entry.value() {
+                        tags.push(value.to_string());
+                    }
+                }
+            }
+        }
+        tags.extend(self.tags.iter().cloned());
+        let alternatives: Vec<String> = tags
+            .iter()
+            .map(|t| t.trim())
+            .filter(|t| !t.is_empty())
+            .map(regex::escape)
+            .collect();
+        // "TODO(alice): text", "FIXME - text", "XXX text" at the start of a
+        // comment, after any extra comment punctuation such as "///" or " * "
+        let marker = Regex::new(&format!(
+            r"^[\s/*!#;\-]*\b(?P<tag>{})\b(?:\((?P<assignee>[^)]*)\))?[:\-\s]*(?P<text>.*)",
+            alternatives.join("|")
+        ))
+        .unwrap();
+
+        let tree = commit.tree()?;
+        let mut classifier = Classifier::for_tree(git_repo, &tree)?;
+        let mut blobs = Vec::new();
+        tree.walk(TreeWalkMode::PreOrder, |dir, entry| {
+            if entry.kind() == Some(ObjectType::Blob) {
+                blobs.push((format!("{}{}", dir, entry.name().unwrap_or("")), entry.id()));
+            }
+            TreeWalkResult::Ok
+        })?;
+
+        let now = Utc::now().timestamp();
+
+        for (path, id) in blobs {
+            let blob = git_repo.find_blob(id)?;
+            if blob.is_binary() || parse_pointer(blob.content()).is_some() {
+                continue;
+            }
+            let language = classifier
+                .classify(&path, || Some(blob.content().to_vec()))
+                .language;
+            let content = String::from_utf8_lossy(blob.content());
+            // Markers only count inside comments; first one on each line
+            let mut found: Vec<(usize, regex::Captures)> = Vec::new();
+            for (line_number, comment) in comments(language.as_deref(), &content) {
+                if found.last().is_some_and(|(last, _)| *last == line_number) {
+                    continue;
+                }
+                if let Some(caps) = marker.captures(comment) {
+                    found.push((line_number, caps));
+                }
+            }
+            if found.is_empty() {
+                continue;
+            }
+
+            // Only files with markers are blamed
+            let lines = blame_lines(git_repo, &path, Some(commit.id()), true).unwrap_or_default();
+
+            for (line_number, caps) in found {
+                let tag = &caps["tag"];
+                // Closing markers the comment syntax did not consume, as in "# TODO */"
+                let text = caps["text"].trim();
+                let text = text
+                    .strip_suffix("*/")
+                    .or_else(|| text.strip_suffix("-->"))
+                    .unwrap_or(text)
+                    .trim_end();
+                let assignee = caps
+                    .name("assignee")
+                    .map(|m| m.as_str().trim())
+                    .filter(|a| !a.is_empty());
+                let blame = lines
+                    .get(line_number - 1)
+                    .filter(|l| l.line_number == line_number);
+                let authored_at = blame.map(|l| l.authored_at.seconds());
+
+                stmt.execute((
+                    &path,
+                    line_number as i64,
+                    tag,
+                    text,
+                    assignee,
+                    blame.map(|l| l.commit_id.to_string()),
+                    blame.map(|l| l.author_name.as_str()),
+                    blame.map(|l| l.author_email.as_str()),
+                    authored_at.map(format_time),
+                    authored_at.map(|t| (now - t) / 86400),
+                    &repo_path,
+                ))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn format_time(timestamp: i64) -> String {
+    if let Some(dt) = Utc.timestamp_opt(timestamp, 0).single() {
+        dt.format("%Y-%m-%d %H:%M:%S").to_string()
+    } else {
+        timestamp.to_string()
+    }
+}
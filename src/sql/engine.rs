@@ -1,31 +1,86 @@
 use crate::error::{Result, VcsqlError};
 use crate::git::GitRepo;
 use crate::providers::{
-    BlameProvider, BranchesProvider, CommitParentsProvider, CommitsProvider, ConfigProvider,
-    DiffFilesProvider, DiffsProvider, HooksProvider, NotesProvider, Provider, ReflogProvider,
-    RefsProvider, RemotesProvider, StashesProvider, StatusProvider, SubmodulesProvider,
-    TagsProvider, WorktreesProvider,
+    BlameProvider, BranchReportProvider, BranchesProvider, CodeownersProvider,
+    CommitBranchesProvider, CommitGraphProvider, CommitParentsProvider, CommitReferencesProvider,
+    CommitTagsProvider, CommitsProvider, ConfigProvider, DiffFilesProvider, DiffsProvider,
+    FileCouplingProvider, FileOwnershipProvider, FileStatsProvider, GitattributesProvider,
+    GitignoreRulesProvider, HooksProvider, LfsObjectsProvider, LostCommitsProvider, NotesProvider,
+    ObjectsProvider, OdbStatsProvider, PackfilesProvider, PatchIdsProvider, Provider,
+    ReflogProvider, RefsProvider, RemotesProvider, ReplaceRefsProvider, RevertsProvider,
+    ShallowProvider, StashesProvider, StatusProvider, SubmoduleUpdatesProvider, SubmodulesProvider,
+    TagsProvider, TodosProvider, WorktreesProvider,
 };
+use crate::sql::functions::{register_functions, RepoRegistry};
 use crate::sql::schema::{get_table_info, TABLES};
+use git2::Repository;
 use regex::Regex;
 use rusqlite::{Connection, Row};
 use serde_json::{Map, Value};
 use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+
+/// Settings that change how tables are populated.
+#[derive(Debug, Clone, Default)]
+pub struct EngineOptions {
+    /// List ignored files in `status`
+    pub include_ignored: bool,
+    /// Branch the `branch_report` table compares against
+    pub base_branch: Option<String>,
+    /// Extra `kind=regex` patterns for `commit_references`
+    pub reference_patterns: Vec<String>,
+    /// Walk only first parents when listing commits
+    pub first_parent: bool,
+    /// Fewest shared commits for a `file_coupling` pair
+    pub coupling_min_count: Option<usize>,
+    /// Largest commit, in files, counted by `file_coupling`
+    pub coupling_max_files: Option<usize>,
+    /// Revision the `codeowners` table reads from
+    pub codeowners_rev: Option<String>,
+    /// Extra markers for `todos`
+    pub todo_tags: Vec<String>,
+    /// Revision the `todos` table scans
+    pub todos_rev: Option<String>,
+}
 
 pub struct SqlEngine {
     conn: Connection,
-    loaded_tables: HashSet<String>,
+    options: EngineOptions,
+    /// (table, repository path) pairs already populated
+    loaded_tables: HashSet<(String, String)>,
+    repos: RepoRegistry,
 }
 
 impl SqlEngine {
     pub fn new() -> Result<Self> {
+        Self::with_options(EngineOptions::default())
+    }
+
+    pub fn with_options(options: EngineOptions) -> Result<Self> {
         let conn = Connection::open_in_memory()?;
+        let repos: RepoRegistry = Arc::new(Mutex::new(Vec::new()));
+        register_functions(&conn, &repos)?;
         Ok(Self {
             conn,
+            options,
             loaded_tables: HashSet::new(),
+            repos,
         })
     }
 
+    /// Make a repository available to the git SQL functions.
+    pub fn register_repo(&mut self, repo: &GitRepo) -> Result<()> {
+        let mut repos = self
+            .repos
+            .lock()
+            .map_err(|e| VcsqlError::Other(e.to_string()))?;
+        if !repos.iter().any(|(path, _)| path == repo.path()) {
+            let handle = Repository::open(repo.inner().path())?;
+            repos.push((repo.path().to_string(), handle));
+        }
+        Ok(())
+    }
+
     pub fn extract_table_names(query: &str) -> HashSet<String> {
         let mut tables = HashSet::new();
 
@@ -55,7 +110,8 @@ impl SqlEngine {
     }
 
     pub fn load_table(&mut self, table_name: &str, repo: &mut GitRepo) -> Result<()> {
-        if self.loaded_tables.contains(table_name) {
+        let key = (table_name.to_string(), repo.path().to_string());
+        if self.loaded_tables.contains(&key) {
             return Ok(());
         }
 
@@ -67,31 +123,68 @@ impl SqlEngine {
         let provider: Box<dyn Provider> = match table_name {
             "commits" => Box::new(CommitsProvider),
             "commit_parents" => Box::new(CommitParentsProvider),
+            "commit_graph" => Box::new(CommitGraphProvider),
+            "commit_references" => Box::new(CommitReferencesProvider::new(
+                self.options.reference_patterns.clone(),
+            )),
+            "shallow" => Box::new(ShallowProvider),
             "branches" => Box::new(BranchesProvider),
+            "branch_report" => {
+                Box::new(BranchReportProvider::new(self.options.base_branch.clone()))
+            }
             "tags" => Box::new(TagsProvider),
+            "commit_branches" => Box::new(CommitBranchesProvider),
+            "commit_tags" => Box::new(CommitTagsProvider),
             "refs" => Box::new(RefsProvider),
             "stashes" => Box::new(StashesProvider),
             "reflog" => Box::new(ReflogProvider),
+            "lost_commits" => Box::new(LostCommitsProvider),
+            "replace_refs" => Box::new(ReplaceRefsProvider),
             "diffs" => Box::new(DiffsProvider),
             "diff_files" => Box::new(DiffFilesProvider),
+            "patch_ids" => Box::new(PatchIdsProvider),
+            "reverts" => Box::new(RevertsProvider),
             "blame" => Box::new(BlameProvider::new(None)),
+            "lfs_objects" => Box::new(LfsObjectsProvider),
+            "submodule_updates" => Box::new(SubmoduleUpdatesProvider),
             "config" => Box::new(ConfigProvider),
             "remotes" => Box::new(RemotesProvider),
             "submodules" => Box::new(SubmodulesProvider),
-            "status" => Box::new(StatusProvider),
+            "gitattributes" => Box::new(GitattributesProvider),
+            "status" => Box::new(StatusProvider::new(self.options.include_ignored)),
+            "gitignore_rules" => Box::new(GitignoreRulesProvider),
+            "codeowners" => Box::new(CodeownersProvider::new(self.options.codeowners_rev.clone())),
             "worktrees" => Box::new(WorktreesProvider),
             "hooks" => Box::new(HooksProvider),
             "notes" => Box::new(NotesProvider),
+            "packfiles" => Box::new(PackfilesProvider),
+            "odb_stats" => Box::new(OdbStatsProvider),
+            "objects" => Box::new(ObjectsProvider),
+            "file_coupling" => Box::new(FileCouplingProvider::new(
+                self.options.coupling_min_count,
+                self.options.coupling_max_files,
+            )),
+            "file_stats" => Box::new(FileStatsProvider),
+            "file_ownership" => Box::new(FileOwnershipProvider),
+            "todos" => Box::new(TodosProvider::new(
+                self.options.todo_tags.clone(),
+                self.options.todos_rev.clone(),
+            )),
             _ => return Err(VcsqlError::TableNotFound(table_name.to_string())),
         };
 
-        provider.populate(&self.conn, repo)?;
-        self.loaded_tables.insert(table_name.to_string());
+        repo.set_first_parent(self.options.first_parent);
+        // One transaction per table instead of one per inserted row
+        let tx = self.conn.unchecked_transaction()?;
+        provider.populate(&tx, repo)?;
+        tx.commit()?;
+        self.loaded_tables.insert(key);
 
         Ok(())
     }
 
     pub fn load_tables_for_query(&mut self, query: &str, repo: &mut GitRepo) -> Result<()> {
+        self.register_repo(repo)?;
         let tables = Self::extract_table_names(query);
         for table in tables {
             self.load_table(&table, repo)?;
@@ -110,8 +203,7 @@ impl SqlEngine {
 
         let rows: Vec<Vec<Value>> = stmt
             .query_map([], |row| Ok(row_to_values(row, column_names.len())))?
-            .filter_map(|r| r.ok())
-            .collect();
+            .collect::<std::result::Result<_, _>>()?;
 
         Ok(QueryResult {
             columns: column_names,
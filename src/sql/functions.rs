@@ -0,0 +1,150 @@
+use crate::error::Result;
+use crate::git::codeowners::{find_codeowners, matching_owners, parse_codeowners, CodeownersRule};
+use crate::git::ignore::{load_ignore_rules, matching_rule, IgnoreRule};
+use crate::sql::{loc, merge_preview};
+use git2::{AttrCheckFlags, AttrValue, Oid, Repository};
+use rusqlite::functions::{Context, FunctionFlags};
+use rusqlite::Connection;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// Repositories opened for the current query, keyed by their `repo` column value.
+pub type RepoRegistry = Arc<Mutex<Vec<(String, Repository)>>>;
+
+/// Register the git-aware SQL functions. Each takes an optional trailing
+/// `repo` argument; without it the first repository given on the command
+/// line is used.
+pub fn register_functions(conn: &Connection, repos: &RepoRegistry) -> Result<()> {
+    for n_arg in [2, 3] {
+        let repos = Arc::clone(repos);
+        conn.create_scalar_function("git_attr", n_arg, FunctionFlags::SQLITE_UTF8, move |ctx| {
+            let path: String = ctx.get(0)?;
+            let name: String = ctx.get(1)?;
+            with_repo(&repos, ctx, 2, |repo| git_attr(repo, &path, &name))
+        })?;
+    }
+
+    for n_arg in [1, 2] {
+        let repos = Arc::clone(repos);
+        // Rules are parsed once per repository rather than once per row
+        let mut rule_cache: HashMap<PathBuf, Vec<IgnoreRule>> = HashMap::new();
+        conn.create_scalar_function("git_is_ignored", n_arg, FunctionFlags::SQLITE_UTF8, move |ctx| {
+            let path: String = ctx.get(0)?;
+            with_repo(&repos, ctx, 1, |repo| {
+                let rules = rule_cache
+                    .entry(repo.path().to_path_buf())
+                    .or_insert_with(|| load_ignore_rules(repo));
+                Ok(git_is_ignored(repo, rules, &path))
+            })
+        })?;
+    }
+
+    for n_arg in [1, 2, 3] {
+        let repos = Arc::clone(repos);
+        // Each revision's CODEOWNERS blob is looked up once, and each blob parsed once
+        let mut blob_cache: HashMap<(PathBuf, String), Option<Oid>> = HashMap::new();
+        let mut rule_cache: HashMap<Oid, Vec<CodeownersRule>> = HashMap::new();
+        conn.create_scalar_function("git_codeowners", n_arg, FunctionFlags::SQLITE_UTF8, move |ctx| {
+            let path: String = ctx.get(0)?;
+            let rev: Option<String> = if ctx.len() > 1 { ctx.get(1)? } else { None };
+            with_repo(&repos, ctx, 2, |repo| {
+                let rev = rev.unwrap_or_else(|| "HEAD".to_string());
+                let key = (repo.path().to_path_buf(), rev);
+                let blob_id = match blob_cache.get(&key) {
+                    Some(id) => *id,
+                    None => {
+                        let id = find_codeowners(repo, Some(&key.1))
+                            .map_err(user_error)?
+                            .map(|(_, id)| id);
+                        blob_cache.insert(key, id);
+                        id
+                    }
+                };
+                let Some(blob_id) = blob_id else {
+                    return Ok(None);
+                };
+                let rules = match rule_cache.entry(blob_id) {
+                    Entry::Occupied(entry) => entry.into_mut(),
+                    Entry::Vacant(entry) => {
+                        let blob = repo.find_blob(blob_id).map_err(user_error)?;
+                        entry.insert(parse_codeowners(&String::from_utf8_lossy(blob.content())))
+                    }
+                };
+                Ok(git_codeowners(rules, &path))
+            })
+        })?;
+    }
+
+    merge_preview::load_module(conn, repos)?;
+    loc::load_module(conn, repos)?;
+
+    Ok(())
+}
+
+/// Run `f` against the repository named by argument `repo_arg`, or the first one.
+fn with_repo<T>(
+    repos: &RepoRegistry,
+    ctx: &Context<'_>,
+    repo_arg: usize,
+    f: impl FnOnce(&Repository) -> rusqlite::Result<T>,
+) -> rusqlite::Result<T> {
+    let wanted: Option<String> = if ctx.len() > repo_arg {
+        ctx.get(repo_arg)?
+    } else {
+        None
+    };
+
+    let repos = repos
+        .lock()
+        .map_err(|e| rusqlite::Error::UserFunctionError(e.to_string().into()))?;
+    let found = match &wanted {
+        Some(name) => repos.iter().find(|(path, _)| path == name),
+        None => repos.first(),
+    };
+
+    match found {
+        Some((_, repo)) => f(repo),
+        None => Err(rusqlite::Error::UserFunctionError(
+            format!("repository not loaded: {}", wanted.unwrap_or_default()).into(),
+        )),
+    }
+}
+
+fn user_error(e: git2::Error) -> rusqlite::Error {
+    rusqlite::Error::UserFunctionError(Box::new(e))
+}
+
+/// Attribute value in `git check-attr` terms: "set", "unset", the string
+/// value, or NULL when unspecified.
+fn git_attr(repo: &Repository, path: &str, name: &str) -> rusqlite::Result<Option<String>> {
+    let value = repo
+        .get_attr(Path::new(path), name, AttrCheckFlags::default())
+        .map_err(user_error)?;
+
+    Ok(match AttrValue::from_string(value) {
+        AttrValue::True => Some("set".to_string()),
+        AttrValue::False => Some("unset".to_string()),
+        AttrValue::String(s) => Some(s.to_string()),
+        AttrValue::Bytes(b) => Some(String::from_utf8_lossy(b).to_string()),
+        AttrValue::Unspecified => None,
+    })
+}
+
+/// The rule ignoring `path` as `source:line:pattern`, like `git check-ignore -v`.
+/// NULL when no rule matches or the last match is a `!` rule re-including it.
+fn git_is_ignored(repo: &Repository, rules: &[IgnoreRule], path: &str) -> Option<String> {
+    let is_dir = repo.workdir().is_some_and(|w| w.join(path).is_dir());
+    matching_rule(rules, path, is_dir)
+        .filter(|rule| !rule.pattern.negated)
+        .map(|rule| rule.describe())
+}
+
+/// Owners of `path` as a space-separated list, from the last matching
+/// CODEOWNERS rule. NULL when no rule matches or the rule names no owners.
+fn git_codeowners(rules: &[CodeownersRule], path: &str) -> Option<String> {
+    matching_owners(rules, path)
+        .filter(|rule| !rule.owners.is_empty())
+        .map(|rule| rule.owners.join(" "))
+}
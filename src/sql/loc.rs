@@ -0,0 +1,266 @@
+use crate::git::language::{count_lines, Classifier, LineCounts};
+use crate::git::lfs::parse_pointer;
+use crate::sql::functions::RepoRegistry;
+use git2::{ErrorCode, ObjectType, Repository, TreeWalkMode, TreeWalkResult};
+use rusqlite::ffi;
+use rusqlite::vtab::{
+    eponymous_only_module, Context, IndexConstraintOp, IndexInfo, VTab, VTabConfig, VTabConnection,
+    VTabCursor, Values,
+};
+use rusqlite::{Connection, Error, Result};
+use std::marker::PhantomData;
+use std::os::raw::c_int;
+
+// Argument columns, hidden from `SELECT *`
+const COLUMN_REV: c_int = 8;
+const COLUMN_REPO: c_int = 9;
+
+const PLAN_REV: c_int = 1;
+const PLAN_REPO: c_int = 2;
+
+/// Register `loc`, readable as a table (at HEAD) or as `loc(rev[, repo])`.
+pub fn load_module(conn: &Connection, repos: &RepoRegistry) -> Result<()> {
+    conn.create_module(
+        "loc",
+        eponymous_only_module::<LocTab>(),
+        Some(RepoRegistry::clone(repos)),
+    )
+}
+
+#[repr(C)]
+struct LocTab {
+    /// Base class. Must be first
+    base: ffi::sqlite3_vtab,
+    repos: RepoRegistry,
+}
+
+unsafe impl<'vtab> VTab<'vtab> for LocTab {
+    type Aux = RepoRegistry;
+    type Cursor = LocCursor<'vtab>;
+
+    fn connect(
+        db: &mut VTabConnection,
+        aux: Option<&RepoRegistry>,
+        _args: &[&[u8]],
+    ) -> Result<(String, Self)> {
+        let repos = aux
+            .cloned()
+            .ok_or_else(|| Error::ModuleError("loc: no repositories".to_string()))?;
+        db.config(VTabConfig::Innocuous)?;
+        Ok((
+            "CREATE TABLE x(path, language, category, lines, blank_lines, comment_lines, \
+             code_lines, commit_id, rev HIDDEN, repo HIDDEN)"
+                .to_owned(),
+            Self {
+                base: ffi::sqlite3_vtab::default(),
+                repos,
+            },
+        ))
+    }
+
+    fn best_index(&self, info: &mut IndexInfo) -> Result<()> {
+        let mut plan = 0;
+        let mut unusable = 0;
+        let mut args: [Option<usize>; 2] = [None, None];
+        for (i, constraint) in info.constraints().enumerate() {
+            let (slot, bit) = match constraint.column() {
+                COLUMN_REV => (0, PLAN_REV),
+                COLUMN_REPO => (1, PLAN_REPO),
+                _ => continue,
+            };
+            if !constraint.is_usable() {
+                unusable |= bit;
+            } else if constraint.operator() == IndexConstraintOp::SQLITE_INDEX_CONSTRAINT_EQ {
+                plan |= bit;
+                args[slot] = Some(i);
+            }
+        }
+        // A join must supply the revision before the scan, not filter on it afterwards
+        if unusable & !plan != 0 {
+            return Err(Error::SqliteFailure(
+                ffi::Error::new(ffi::SQLITE_CONSTRAINT),
+                None,
+            ));
+        }
+
+        let mut n_arg = 0;
+        for i in args.iter().flatten() {
+            n_arg += 1;
+            let mut usage = info.constraint_usage(*i);
+            usage.set_argv_index(n_arg);
+            usage.set_omit(true);
+        }
+        info.set_idx_num(plan);
+        info.set_estimated_cost(1000.0);
+        Ok(())
+    }
+
+    fn open(&'vtab mut self) -> Result<LocCursor<'vtab>> {
+        Ok(LocCursor {
+            base: ffi::sqlite3_vtab_cursor::default(),
+            repos: RepoRegistry::clone(&self.repos),
+            rows: Vec::new(),
+            row: 0,
+            phantom: PhantomData,
+        })
+    }
+}
+
+fn loc_error(e: git2::Error) -> Error {
+    Error::ModuleError(format!("loc: {}", e.message()))
+}
+
+/// One output row; the trailing fields repeat for every file of a revision.
+struct LocRow {
+    path: String,
+    language: Option<String>,
+    category: &'static str,
+    counts: LineCounts,
+    commit_id: String,
+    rev: String,
+    repo: String,
+}
+
+#[repr(C)]
+struct LocCursor<'vtab> {
+    /// Base class. Must be first
+    base: ffi::sqlite3_vtab_cursor,
+    repos: RepoRegistry,
+    rows: Vec<LocRow>,
+    row: usize,
+    phantom: PhantomData<&'vtab LocTab>,
+}
+
+unsafe impl VTabCursor for LocCursor<'_> {
+    fn filter(&mut self, plan: c_int, _idx_str: Option<&str>, args: &Values<'_>) -> Result<()> {
+        let mut next_arg = 0;
+        let rev: Option<String> = if plan & PLAN_REV != 0 {
+            next_arg += 1;
+            args.get(0)?
+        } else {
+            None
+        };
+        let wanted: Option<String> = if plan & PLAN_REPO != 0 {
+            args.get(next_arg)?
+        } else {
+            None
+        };
+
+        let repos = self
+            .repos
+            .lock()
+            .map_err(|e| Error::ModuleError(e.to_string()))?;
+        // Without a repo argument, every repository is counted like the other tables
+        let targets: Vec<_> = match &wanted {
+            Some(name) => match repos.iter().find(|(path, _)| path == name) {
+                Some(found) => vec![found],
+                None => {
+                    return Err(Error::ModuleError(format!(
+                        "repository not loaded: {}",
+                        name
+                    )))
+                }
+            },
+            None => repos.iter().collect(),
+        };
+
+        let mut rows = Vec::new();
+        let mut counted_any = false;
+        let mut missing: Option<git2::Error> = None;
+        for (repo_path, repo) in targets {
+            let counted = match &rev {
+                Some(rev) => count_revision(repo, repo_path, rev),
+                // An unborn HEAD simply has no files yet
+                None if repo.head().is_err() => Ok(Vec::new()),
+                None => count_revision(repo, repo_path, "HEAD"),
+            };
+            match counted {
+                Ok(counted) => {
+                    rows.extend(counted);
+                    counted_any = true;
+                }
+                // A revision only has to exist in one of the repositories
+                Err(e) if e.code() == ErrorCode::NotFound => missing = Some(e),
+                Err(e) => return Err(loc_error(e)),
+            }
+        }
+        if let Some(e) = missing.filter(|_| !counted_any) {
+            return Err(loc_error(e));
+        }
+        self.rows = rows;
+        self.row = 0;
+        Ok(())
+    }
+
+    fn next(&mut self) -> Result<()> {
+        self.row += 1;
+        Ok(())
+    }
+
+    fn eof(&self) -> bool {
+        self.row >= self.rows.len()
+    }
+
+    fn column(&self, ctx: &mut Context, i: c_int) -> Result<()> {
+        let row = &self.rows[self.row];
+        match i {
+            0 => ctx.set_result(&row.path),
+            1 => ctx.set_result(&row.language),
+            2 => ctx.set_result(&row.category),
+            3 => ctx.set_result(&row.counts.lines),
+            4 => ctx.set_result(&row.counts.blank),
+            5 => ctx.set_result(&row.counts.comment),
+            6 => ctx.set_result(&row.counts.code),
+            7 => ctx.set_result(&row.commit_id),
+            COLUMN_REV => ctx.set_result(&row.rev),
+            _ => ctx.set_result(&row.repo),
+        }
+    }
+
+    fn rowid(&self) -> Result<i64> {
+        Ok(self.row as i64)
+    }
+}
+
+/// Count lines in every text file of `rev`'s tree, reading blobs directly.
+/// Binary files and LFS pointers are skipped.
+fn count_revision(
+    repo: &Repository,
+    repo_path: &str,
+    rev: &str,
+) -> std::result::Result<Vec<LocRow>, git2::Error> {
+    let commit = repo.revparse_single(rev)?.peel_to_commit()?;
+    let commit_id = commit.id().to_string();
+
+    let tree = commit.tree()?;
+    let mut blobs = Vec::new();
+    tree.walk(TreeWalkMode::PreOrder, |dir, entry| {
+        if entry.kind() == Some(ObjectType::Blob) {
+            blobs.push((format!("{}{}", dir, entry.name().unwrap_or("")), entry.id()));
+        }
+        TreeWalkResult::Ok
+    })?;
+
+    // Attributes as of `rev`, not today's work tree
+    let mut classifier = Classifier::for_tree(repo, &tree)?;
+    let mut rows = Vec::new();
+    for (path, id) in blobs {
+        let blob = repo.find_blob(id)?;
+        // The blob is loaded already, so pointers are recognised from its content
+        if blob.is_binary() || parse_pointer(blob.content()).is_some() {
+            continue;
+        }
+        let text = String::from_utf8_lossy(blob.content());
+        let classification = classifier.classify(&path, || Some(blob.content().to_vec()));
+        rows.push(LocRow {
+            counts: count_lines(classification.language.as_deref(), &text),
+            path,
+            language: classification.language,
+            category: classification.category,
+            commit_id: commit_id.clone(),
+            rev: rev.to_string(),
+            repo: repo_path.to_string(),
+        });
+    }
+    Ok(rows)
+}
@@ -0,0 +1,262 @@
+use crate::sql::functions::RepoRegistry;
+use git2::{IndexConflict, Repository};
+use rusqlite::ffi;
+use rusqlite::vtab::{
+    eponymous_only_module, Context, IndexConstraintOp, IndexInfo, VTab, VTabConfig,
+    VTabConnection, VTabCursor, Values,
+};
+use rusqlite::{Connection, Error, Result};
+use std::marker::PhantomData;
+use std::os::raw::c_int;
+
+// Argument columns, hidden from `SELECT *`
+const COLUMN_OURS: c_int = 7;
+const COLUMN_THEIRS: c_int = 8;
+const COLUMN_REPO: c_int = 9;
+
+const PLAN_OURS: c_int = 1;
+const PLAN_THEIRS: c_int = 2;
+const PLAN_REPO: c_int = 4;
+
+/// Register `merge_preview(ours, theirs[, repo])`, which merges two revisions
+/// in memory and lists the paths that would conflict.
+pub fn load_module(conn: &Connection, repos: &RepoRegistry) -> Result<()> {
+    conn.create_module(
+        "merge_preview",
+        eponymous_only_module::<MergePreviewTab>(),
+        Some(RepoRegistry::clone(repos)),
+    )
+}
+
+#[repr(C)]
+struct MergePreviewTab {
+    /// Base class. Must be first
+    base: ffi::sqlite3_vtab,
+    repos: RepoRegistry,
+}
+
+unsafe impl<'vtab> VTab<'vtab> for MergePreviewTab {
+    type Aux = RepoRegistry;
+    type Cursor = MergePreviewCursor<'vtab>;
+
+    fn connect(
+        db: &mut VTabConnection,
+        aux: Option<&RepoRegistry>,
+        _args: &[&[u8]],
+    ) -> Result<(String, Self)> {
+        let repos = aux
+            .cloned()
+            .ok_or_else(|| Error::ModuleError("merge_preview: no repositories".to_string()))?;
+        db.config(VTabConfig::Innocuous)?;
+        Ok((
+            "CREATE TABLE x(path, conflict_type, ancestor_id, ours_id, theirs_id, is_clean, \
+             merge_base_id, ours HIDDEN, theirs HIDDEN, repo HIDDEN)"
+                .to_owned(),
+            Self {
+                base: ffi::sqlite3_vtab::default(),
+                repos,
+            },
+        ))
+    }
+
+    fn best_index(&self, info: &mut IndexInfo) -> Result<()> {
+        let mut plan = 0;
+        let mut unusable = 0;
+        let mut args: [Option<usize>; 3] = [None, None, None];
+        for (i, constraint) in info.constraints().enumerate() {
+            let (slot, bit) = match constraint.column() {
+                COLUMN_OURS => (0, PLAN_OURS),
+                COLUMN_THEIRS => (1, PLAN_THEIRS),
+                COLUMN_REPO => (2, PLAN_REPO),
+                _ => continue,
+            };
+            if !constraint.is_usable() {
+                unusable |= bit;
+            } else if constraint.operator() == IndexConstraintOp::SQLITE_INDEX_CONSTRAINT_EQ {
+                plan |= bit;
+                args[slot] = Some(i);
+            }
+        }
+        if unusable & !plan != 0 {
+            return Err(Error::SqliteFailure(ffi::Error::new(ffi::SQLITE_CONSTRAINT), None));
+        }
+
+        let mut n_arg = 0;
+        for i in args.iter().flatten() {
+            n_arg += 1;
+            let mut usage = info.constraint_usage(*i);
+            usage.set_argv_index(n_arg);
+            usage.set_omit(true);
+        }
+        info.set_idx_num(plan);
+        info.set_estimated_cost(1.0);
+        Ok(())
+    }
+
+    fn open(&'vtab mut self) -> Result<MergePreviewCursor<'vtab>> {
+        Ok(MergePreviewCursor {
+            base: ffi::sqlite3_vtab_cursor::default(),
+            repos: RepoRegistry::clone(&self.repos),
+            rows: Vec::new(),
+            row: 0,
+            phantom: PhantomData,
+        })
+    }
+}
+
+/// One output row; the trailing fields repeat for every row of a preview.
+struct PreviewRow {
+    path: Option<String>,
+    conflict_type: Option<&'static str>,
+    ancestor_id: Option<String>,
+    ours_id: Option<String>,
+    theirs_id: Option<String>,
+    is_clean: bool,
+    merge_base_id: Option<String>,
+    ours: String,
+    theirs: String,
+    repo: String,
+}
+
+#[repr(C)]
+struct MergePreviewCursor<'vtab> {
+    /// Base class. Must be first
+    base: ffi::sqlite3_vtab_cursor,
+    repos: RepoRegistry,
+    rows: Vec<PreviewRow>,
+    row: usize,
+    phantom: PhantomData<&'vtab MergePreviewTab>,
+}
+
+unsafe impl VTabCursor for MergePreviewCursor<'_> {
+    fn filter(&mut self, plan: c_int, _idx_str: Option<&str>, args: &Values<'_>) -> Result<()> {
+        if plan & (PLAN_OURS | PLAN_THEIRS) != PLAN_OURS | PLAN_THEIRS {
+            return Err(Error::ModuleError(
+                "merge_preview requires two revisions: merge_preview(ours, theirs[, repo])"
+                    .to_string(),
+            ));
+        }
+        let ours: String = args.get(0)?;
+        let theirs: String = args.get(1)?;
+        let wanted: Option<String> = if plan & PLAN_REPO != 0 {
+            args.get(2)?
+        } else {
+            None
+        };
+
+        let repos = self
+            .repos
+            .lock()
+            .map_err(|e| Error::ModuleError(e.to_string()))?;
+        let found = match &wanted {
+            Some(name) => repos.iter().find(|(path, _)| path == name),
+            None => repos.first(),
+        };
+        let Some((repo_path, repo)) = found else {
+            return Err(Error::ModuleError(format!(
+                "repository not loaded: {}",
+                wanted.unwrap_or_default()
+            )));
+        };
+
+        self.rows = preview(repo, repo_path, &ours, &theirs)
+            .map_err(|e| Error::ModuleError(format!("merge_preview: {}", e.message())))?;
+        self.row = 0;
+        Ok(())
+    }
+
+    fn next(&mut self) -> Result<()> {
+        self.row += 1;
+        Ok(())
+    }
+
+    fn eof(&self) -> bool {
+        self.row >= self.rows.len()
+    }
+
+    fn column(&self, ctx: &mut Context, i: c_int) -> Result<()> {
+        let row = &self.rows[self.row];
+        match i {
+            0 => ctx.set_result(&row.path),
+            1 => ctx.set_result(&row.conflict_type),
+            2 => ctx.set_result(&row.ancestor_id),
+            3 => ctx.set_result(&row.ours_id),
+            4 => ctx.set_result(&row.theirs_id),
+            5 => ctx.set_result(&(if row.is_clean { 1 } else { 0 })),
+            6 => ctx.set_result(&row.merge_base_id),
+            COLUMN_OURS => ctx.set_result(&row.ours),
+            COLUMN_THEIRS => ctx.set_result(&row.theirs),
+            _ => ctx.set_result(&row.repo),
+        }
+    }
+
+    fn rowid(&self) -> Result<i64> {
+        Ok(self.row as i64)
+    }
+}
+
+/// Merge `theirs` into `ours` in memory. A clean merge yields a single row
+/// with no path; otherwise there is one row per conflicting path.
+fn preview(
+    repo: &Repository,
+    repo_path: &str,
+    ours: &str,
+    theirs: &str,
+) -> std::result::Result<Vec<PreviewRow>, git2::Error> {
+    let our_commit = repo.revparse_single(ours)?.peel_to_commit()?;
+    let their_commit = repo.revparse_single(theirs)?.peel_to_commit()?;
+    let merge_base_id = repo
+        .merge_base(our_commit.id(), their_commit.id())
+        .ok()
+        .map(|oid| oid.to_string());
+
+    let index = repo.merge_commits(&our_commit, &their_commit, None)?;
+
+    let row = |conflict: Option<IndexConflict>| {
+        let (path, conflict_type, ancestor_id, ours_id, theirs_id) = match conflict {
+            Some(conflict) => {
+                let path = [&conflict.our, &conflict.their, &conflict.ancestor]
+                    .into_iter()
+                    .flatten()
+                    .next()
+                    .map(|entry| String::from_utf8_lossy(&entry.path).to_string());
+                let conflict_type = match (&conflict.ancestor, &conflict.our, &conflict.their) {
+                    (None, _, _) => "add/add",
+                    (Some(_), None, _) => "delete/modify",
+                    (Some(_), _, None) => "modify/delete",
+                    _ => "content",
+                };
+                let id = |entry: &Option<git2::IndexEntry>| entry.as_ref().map(|e| e.id.to_string());
+                (
+                    path,
+                    Some(conflict_type),
+                    id(&conflict.ancestor),
+                    id(&conflict.our),
+                    id(&conflict.their),
+                )
+            }
+            None => (None, None, None, None, None),
+        };
+        PreviewRow {
+            path,
+            conflict_type,
+            ancestor_id,
+            ours_id,
+            theirs_id,
+            is_clean: conflict_type.is_none(),
+            merge_base_id: merge_base_id.clone(),
+            ours: ours.to_string(),
+            theirs: theirs.to_string(),
+            repo: repo_path.to_string(),
+        }
+    };
+
+    if !index.has_conflicts() {
+        return Ok(vec![row(None)]);
+    }
+    let mut rows = Vec::new();
+    for conflict in index.conflicts()? {
+        rows.push(row(Some(conflict?)));
+    }
+    Ok(rows)
+}
@@ -1,5 +1,8 @@
 pub mod engine;
+pub mod functions;
+pub mod loc;
+pub mod merge_preview;
 pub mod schema;
 
-pub use engine::{QueryResult, SqlEngine};
+pub use engine::{EngineOptions, QueryResult, SqlEngine};
 pub use schema::{get_table_info, get_tables_by_category, TableInfo, TABLES};
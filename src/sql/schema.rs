@@ -36,6 +36,10 @@ pub static COMMITS_COLUMNS: &[ColumnInfo] = &[
     ColumnInfo { name: "body", sql_type: "TEXT", nullable: true, description: "Message body (lines 2+)" },
     ColumnInfo { name: "parent_count", sql_type: "INTEGER", nullable: false, description: "Number of parents" },
     ColumnInfo { name: "is_merge", sql_type: "BOOLEAN", nullable: false, description: "True if merge commit" },
+    ColumnInfo { name: "is_shallow_boundary", sql_type: "BOOLEAN", nullable: false, description: "Parents cut off by a shallow clone" },
+    ColumnInfo { name: "is_mainline", sql_type: "BOOLEAN", nullable: false, description: "On HEAD's first-parent history" },
+    ColumnInfo { name: "mainline_depth", sql_type: "INTEGER", nullable: true, description: "First-parent steps from HEAD" },
+    ColumnInfo { name: "merged_in_by", sql_type: "TEXT", nullable: true, description: "Mainline merge that brought it in" },
     ColumnInfo { name: "repo", sql_type: "TEXT", nullable: false, description: "Repository path" },
 ];
 
@@ -46,6 +50,35 @@ pub static COMMIT_PARENTS_COLUMNS: &[ColumnInfo] = &[
     ColumnInfo { name: "repo", sql_type: "TEXT", nullable: false, description: "Repository path" },
 ];
 
+pub static COMMIT_GRAPH_COLUMNS: &[ColumnInfo] = &[
+    ColumnInfo { name: "commit_id", sql_type: "TEXT", nullable: false, description: "Commit SHA" },
+    ColumnInfo { name: "generation", sql_type: "INTEGER", nullable: false, description: "1 + highest parent generation (roots are 1)" },
+    ColumnInfo { name: "root_distance", sql_type: "INTEGER", nullable: false, description: "Fewest parent steps to a root" },
+    ColumnInfo { name: "child_count", sql_type: "INTEGER", nullable: false, description: "Commits naming this one as a parent" },
+    ColumnInfo { name: "is_branch_point", sql_type: "BOOLEAN", nullable: false, description: "More than one child" },
+    ColumnInfo { name: "is_tip", sql_type: "BOOLEAN", nullable: false, description: "HEAD or a local or remote branch points at it" },
+    ColumnInfo { name: "merge_depth", sql_type: "INTEGER", nullable: true, description: "Merges between HEAD's first-parent line and this commit (NULL off HEAD's history)" },
+    ColumnInfo { name: "repo", sql_type: "TEXT", nullable: false, description: "Repository path" },
+];
+
+pub static COMMIT_REFERENCES_COLUMNS: &[ColumnInfo] = &[
+    ColumnInfo { name: "commit_id", sql_type: "TEXT", nullable: false, description: "Commit SHA" },
+    ColumnInfo { name: "kind", sql_type: "TEXT", nullable: false, description: "pull_request/merge_request/fixes/jira/custom" },
+    ColumnInfo { name: "key", sql_type: "TEXT", nullable: false, description: "Reference as written (#123, PROJ-45)" },
+    ColumnInfo { name: "number", sql_type: "INTEGER", nullable: true, description: "Numeric part of the key" },
+    ColumnInfo { name: "source_branch", sql_type: "TEXT", nullable: true, description: "Branch named by a merge message" },
+    ColumnInfo { name: "repo", sql_type: "TEXT", nullable: false, description: "Repository path" },
+];
+
+pub static SHALLOW_COLUMNS: &[ColumnInfo] = &[
+    ColumnInfo { name: "commit_id", sql_type: "TEXT", nullable: false, description: "Boundary commit SHA" },
+    ColumnInfo { name: "source", sql_type: "TEXT", nullable: false, description: "shallow/graft" },
+    ColumnInfo { name: "summary", sql_type: "TEXT", nullable: true, description: "First line of message" },
+    ColumnInfo { name: "committed_at", sql_type: "DATETIME", nullable: true, description: "When committed" },
+    ColumnInfo { name: "parent_count", sql_type: "INTEGER", nullable: true, description: "Parents after shallow/graft rewriting" },
+    ColumnInfo { name: "repo", sql_type: "TEXT", nullable: false, description: "Repository path" },
+];
+
 // ============================================================================
 // REFERENCE TABLES
 // ============================================================================
@@ -63,6 +96,24 @@ pub static BRANCHES_COLUMNS: &[ColumnInfo] = &[
     ColumnInfo { name: "repo", sql_type: "TEXT", nullable: false, description: "Repository path" },
 ];
 
+pub static BRANCH_REPORT_COLUMNS: &[ColumnInfo] = &[
+    ColumnInfo { name: "name", sql_type: "TEXT", nullable: false, description: "Branch name" },
+    ColumnInfo { name: "full_name", sql_type: "TEXT", nullable: false, description: "Full refname" },
+    ColumnInfo { name: "is_remote", sql_type: "BOOLEAN", nullable: false, description: "Remote tracking branch" },
+    ColumnInfo { name: "base", sql_type: "TEXT", nullable: false, description: "Branch compared against" },
+    ColumnInfo { name: "merge_base_id", sql_type: "TEXT", nullable: true, description: "Common ancestor with the base" },
+    ColumnInfo { name: "ahead", sql_type: "INTEGER", nullable: false, description: "Commits not in the base" },
+    ColumnInfo { name: "behind", sql_type: "INTEGER", nullable: false, description: "Base commits not in the branch" },
+    ColumnInfo { name: "is_merged", sql_type: "BOOLEAN", nullable: false, description: "Work has landed in the base" },
+    ColumnInfo { name: "merged_via", sql_type: "TEXT", nullable: true, description: "ancestor, squash, or cherry-pick" },
+    ColumnInfo { name: "last_commit_id", sql_type: "TEXT", nullable: false, description: "Tip commit SHA" },
+    ColumnInfo { name: "last_commit_at", sql_type: "DATETIME", nullable: false, description: "Tip commit date" },
+    ColumnInfo { name: "last_author_name", sql_type: "TEXT", nullable: false, description: "Tip author name" },
+    ColumnInfo { name: "last_author_email", sql_type: "TEXT", nullable: false, description: "Tip author email" },
+    ColumnInfo { name: "age_days", sql_type: "INTEGER", nullable: false, description: "Days since the tip commit" },
+    ColumnInfo { name: "repo", sql_type: "TEXT", nullable: false, description: "Repository path" },
+];
+
 pub static TAGS_COLUMNS: &[ColumnInfo] = &[
     ColumnInfo { name: "name", sql_type: "TEXT", nullable: false, description: "Tag name" },
     ColumnInfo { name: "full_name", sql_type: "TEXT", nullable: false, description: "Full refname" },
@@ -76,6 +127,21 @@ pub static TAGS_COLUMNS: &[ColumnInfo] = &[
     ColumnInfo { name: "repo", sql_type: "TEXT", nullable: false, description: "Repository path" },
 ];
 
+pub static COMMIT_BRANCHES_COLUMNS: &[ColumnInfo] = &[
+    ColumnInfo { name: "commit_id", sql_type: "TEXT", nullable: false, description: "Commit SHA" },
+    ColumnInfo { name: "branch", sql_type: "TEXT", nullable: false, description: "Branch containing the commit" },
+    ColumnInfo { name: "full_name", sql_type: "TEXT", nullable: false, description: "Full refname" },
+    ColumnInfo { name: "is_remote", sql_type: "BOOLEAN", nullable: false, description: "Remote tracking branch" },
+    ColumnInfo { name: "repo", sql_type: "TEXT", nullable: false, description: "Repository path" },
+];
+
+pub static COMMIT_TAGS_COLUMNS: &[ColumnInfo] = &[
+    ColumnInfo { name: "commit_id", sql_type: "TEXT", nullable: false, description: "Commit SHA" },
+    ColumnInfo { name: "tag", sql_type: "TEXT", nullable: false, description: "Tag containing the commit" },
+    ColumnInfo { name: "full_name", sql_type: "TEXT", nullable: false, description: "Full refname" },
+    ColumnInfo { name: "repo", sql_type: "TEXT", nullable: false, description: "Repository path" },
+];
+
 pub static REFS_COLUMNS: &[ColumnInfo] = &[
     ColumnInfo { name: "name", sql_type: "TEXT", nullable: false, description: "Short name" },
     ColumnInfo { name: "full_name", sql_type: "TEXT", nullable: false, description: "Full reference name" },
@@ -110,6 +176,28 @@ pub static REFLOG_COLUMNS: &[ColumnInfo] = &[
     ColumnInfo { name: "repo", sql_type: "TEXT", nullable: false, description: "Repository path" },
 ];
 
+pub static LOST_COMMITS_COLUMNS: &[ColumnInfo] = &[
+    ColumnInfo { name: "commit_id", sql_type: "TEXT", nullable: false, description: "Commit SHA" },
+    ColumnInfo { name: "short_id", sql_type: "TEXT", nullable: false, description: "Abbreviated hash" },
+    ColumnInfo { name: "summary", sql_type: "TEXT", nullable: false, description: "First line of message" },
+    ColumnInfo { name: "author_name", sql_type: "TEXT", nullable: false, description: "Author" },
+    ColumnInfo { name: "author_email", sql_type: "TEXT", nullable: false, description: "Email" },
+    ColumnInfo { name: "authored_at", sql_type: "DATETIME", nullable: false, description: "When written" },
+    ColumnInfo { name: "source", sql_type: "TEXT", nullable: false, description: "reflog/stash/dangling" },
+    ColumnInfo { name: "last_ref", sql_type: "TEXT", nullable: true, description: "Last ref that pointed at it" },
+    ColumnInfo { name: "last_seen_at", sql_type: "DATETIME", nullable: true, description: "When that ref last pointed at it" },
+    ColumnInfo { name: "is_tip", sql_type: "BOOLEAN", nullable: false, description: "No lost commit builds on it" },
+    ColumnInfo { name: "repo", sql_type: "TEXT", nullable: false, description: "Repository path" },
+];
+
+pub static REPLACE_REFS_COLUMNS: &[ColumnInfo] = &[
+    ColumnInfo { name: "original_id", sql_type: "TEXT", nullable: false, description: "Replaced object SHA" },
+    ColumnInfo { name: "replacement_id", sql_type: "TEXT", nullable: false, description: "Replacement object SHA" },
+    ColumnInfo { name: "original_type", sql_type: "TEXT", nullable: true, description: "commit/tree/blob/tag" },
+    ColumnInfo { name: "replacement_type", sql_type: "TEXT", nullable: true, description: "commit/tree/blob/tag" },
+    ColumnInfo { name: "repo", sql_type: "TEXT", nullable: false, description: "Repository path" },
+];
+
 // ============================================================================
 // DIFF & CHANGE TABLES
 // ============================================================================
@@ -133,6 +221,26 @@ pub static DIFF_FILES_COLUMNS: &[ColumnInfo] = &[
     ColumnInfo { name: "deletions", sql_type: "INTEGER", nullable: false, description: "Lines removed" },
     ColumnInfo { name: "is_binary", sql_type: "BOOLEAN", nullable: false, description: "Binary file" },
     ColumnInfo { name: "similarity", sql_type: "INTEGER", nullable: true, description: "Rename similarity %" },
+    ColumnInfo { name: "is_lfs", sql_type: "BOOLEAN", nullable: false, description: "Git LFS pointer file" },
+    ColumnInfo { name: "old_size", sql_type: "INTEGER", nullable: true, description: "Size before (LFS-resolved)" },
+    ColumnInfo { name: "new_size", sql_type: "INTEGER", nullable: true, description: "Size after (LFS-resolved)" },
+    ColumnInfo { name: "language", sql_type: "TEXT", nullable: true, description: "Language (linguist-language overrides)" },
+    ColumnInfo { name: "category", sql_type: "TEXT", nullable: true, description: "source/test/docs/config/generated/vendored/other" },
+    ColumnInfo { name: "repo", sql_type: "TEXT", nullable: false, description: "Repository path" },
+];
+
+pub static PATCH_IDS_COLUMNS: &[ColumnInfo] = &[
+    ColumnInfo { name: "commit_id", sql_type: "TEXT", nullable: false, description: "Commit SHA" },
+    ColumnInfo { name: "patch_id", sql_type: "TEXT", nullable: false, description: "Stable patch-id of the change" },
+    ColumnInfo { name: "repo", sql_type: "TEXT", nullable: false, description: "Repository path" },
+];
+
+pub static REVERTS_COLUMNS: &[ColumnInfo] = &[
+    ColumnInfo { name: "commit_id", sql_type: "TEXT", nullable: false, description: "Reverting commit SHA" },
+    ColumnInfo { name: "reverted_id", sql_type: "TEXT", nullable: false, description: "Commit being undone" },
+    ColumnInfo { name: "detected_by", sql_type: "TEXT", nullable: false, description: "message or patch-id" },
+    ColumnInfo { name: "patch_matches", sql_type: "BOOLEAN", nullable: false, description: "Change is the exact inverse" },
+    ColumnInfo { name: "depth", sql_type: "INTEGER", nullable: false, description: "1 = revert, 2 = revert of a revert, ..." },
     ColumnInfo { name: "repo", sql_type: "TEXT", nullable: false, description: "Repository path" },
 ];
 
@@ -146,6 +254,32 @@ pub static BLAME_COLUMNS: &[ColumnInfo] = &[
     ColumnInfo { name: "author_email", sql_type: "TEXT", nullable: false, description: "Email" },
     ColumnInfo { name: "authored_at", sql_type: "DATETIME", nullable: false, description: "When written" },
     ColumnInfo { name: "line_content", sql_type: "TEXT", nullable: false, description: "Line text" },
+    ColumnInfo { name: "language", sql_type: "TEXT", nullable: true, description: "Language (linguist-language overrides)" },
+    ColumnInfo { name: "category", sql_type: "TEXT", nullable: false, description: "source/test/docs/config/generated/vendored/other" },
+    ColumnInfo { name: "repo", sql_type: "TEXT", nullable: false, description: "Repository path" },
+];
+
+pub static LFS_OBJECTS_COLUMNS: &[ColumnInfo] = &[
+    ColumnInfo { name: "commit_id", sql_type: "TEXT", nullable: false, description: "Commit that wrote the pointer" },
+    ColumnInfo { name: "path", sql_type: "TEXT", nullable: false, description: "File path" },
+    ColumnInfo { name: "status", sql_type: "TEXT", nullable: false, description: "A/M/T" },
+    ColumnInfo { name: "pointer_id", sql_type: "TEXT", nullable: false, description: "Pointer blob SHA" },
+    ColumnInfo { name: "lfs_oid", sql_type: "TEXT", nullable: false, description: "SHA-256 of the real content" },
+    ColumnInfo { name: "size", sql_type: "INTEGER", nullable: false, description: "Declared size in bytes" },
+    ColumnInfo { name: "is_present", sql_type: "BOOLEAN", nullable: false, description: "Content in .git/lfs/objects" },
+    ColumnInfo { name: "in_head", sql_type: "BOOLEAN", nullable: false, description: "Pointer still at this path in HEAD" },
+    ColumnInfo { name: "repo", sql_type: "TEXT", nullable: false, description: "Repository path" },
+];
+
+pub static SUBMODULE_UPDATES_COLUMNS: &[ColumnInfo] = &[
+    ColumnInfo { name: "commit_id", sql_type: "TEXT", nullable: false, description: "Commit that changed the gitlink" },
+    ColumnInfo { name: "path", sql_type: "TEXT", nullable: false, description: "Submodule path" },
+    ColumnInfo { name: "old_id", sql_type: "TEXT", nullable: true, description: "Previously pinned commit" },
+    ColumnInfo { name: "new_id", sql_type: "TEXT", nullable: true, description: "Newly pinned commit" },
+    ColumnInfo { name: "change", sql_type: "TEXT", nullable: false, description: "added/updated/removed" },
+    ColumnInfo { name: "direction", sql_type: "TEXT", nullable: true, description: "fast-forward/rewind/diverged" },
+    ColumnInfo { name: "commits_added", sql_type: "INTEGER", nullable: true, description: "Commits in new pin but not old" },
+    ColumnInfo { name: "commits_removed", sql_type: "INTEGER", nullable: true, description: "Commits in old pin but not new" },
     ColumnInfo { name: "repo", sql_type: "TEXT", nullable: false, description: "Repository path" },
 ];
 
@@ -179,6 +313,37 @@ pub static SUBMODULES_COLUMNS: &[ColumnInfo] = &[
     ColumnInfo { name: "branch", sql_type: "TEXT", nullable: true, description: "Tracked branch" },
     ColumnInfo { name: "head_id", sql_type: "TEXT", nullable: true, description: "Current HEAD SHA" },
     ColumnInfo { name: "status", sql_type: "TEXT", nullable: false, description: "current/modified/uninitialized" },
+    ColumnInfo { name: "submodule_repo", sql_type: "TEXT", nullable: true, description: "repo value of the submodule's rows (--recurse-submodules)" },
+    ColumnInfo { name: "repo", sql_type: "TEXT", nullable: false, description: "Repository path" },
+];
+
+pub static GITATTRIBUTES_COLUMNS: &[ColumnInfo] = &[
+    ColumnInfo { name: "source", sql_type: "TEXT", nullable: false, description: "Attributes file" },
+    ColumnInfo { name: "scope", sql_type: "TEXT", nullable: false, description: "tree/info/global" },
+    ColumnInfo { name: "line_number", sql_type: "INTEGER", nullable: false, description: "Line in file" },
+    ColumnInfo { name: "pattern", sql_type: "TEXT", nullable: false, description: "Path pattern or [attr] macro" },
+    ColumnInfo { name: "attribute", sql_type: "TEXT", nullable: false, description: "Attribute name" },
+    ColumnInfo { name: "value", sql_type: "TEXT", nullable: false, description: "set/unset/unspecified or value" },
+    ColumnInfo { name: "repo", sql_type: "TEXT", nullable: false, description: "Repository path" },
+];
+
+pub static GITIGNORE_RULES_COLUMNS: &[ColumnInfo] = &[
+    ColumnInfo { name: "source", sql_type: "TEXT", nullable: false, description: "Ignore file" },
+    ColumnInfo { name: "scope", sql_type: "TEXT", nullable: false, description: "tree/info/global" },
+    ColumnInfo { name: "line_number", sql_type: "INTEGER", nullable: false, description: "Line in file" },
+    ColumnInfo { name: "pattern", sql_type: "TEXT", nullable: false, description: "Pattern as written" },
+    ColumnInfo { name: "is_negated", sql_type: "BOOLEAN", nullable: false, description: "Re-includes matches (!)" },
+    ColumnInfo { name: "is_dir_only", sql_type: "BOOLEAN", nullable: false, description: "Matches directories only" },
+    ColumnInfo { name: "precedence", sql_type: "INTEGER", nullable: false, description: "Higher wins when several match" },
+    ColumnInfo { name: "repo", sql_type: "TEXT", nullable: false, description: "Repository path" },
+];
+
+pub static CODEOWNERS_COLUMNS: &[ColumnInfo] = &[
+    ColumnInfo { name: "source", sql_type: "TEXT", nullable: false, description: "CODEOWNERS file in effect" },
+    ColumnInfo { name: "line_number", sql_type: "INTEGER", nullable: false, description: "Line in file" },
+    ColumnInfo { name: "pattern", sql_type: "TEXT", nullable: false, description: "Pattern as written" },
+    ColumnInfo { name: "owners", sql_type: "TEXT", nullable: true, description: "Space-separated owners, NULL if unowned" },
+    ColumnInfo { name: "owner_count", sql_type: "INTEGER", nullable: false, description: "Number of owners" },
     ColumnInfo { name: "repo", sql_type: "TEXT", nullable: false, description: "Repository path" },
 ];
 
@@ -197,7 +362,7 @@ pub static STATUS_COLUMNS: &[ColumnInfo] = &[
     ColumnInfo { name: "is_deleted", sql_type: "BOOLEAN", nullable: false, description: "Deleted" },
     ColumnInfo { name: "is_renamed", sql_type: "BOOLEAN", nullable: false, description: "Renamed" },
     ColumnInfo { name: "is_copied", sql_type: "BOOLEAN", nullable: false, description: "Copied" },
-    ColumnInfo { name: "is_ignored", sql_type: "BOOLEAN", nullable: false, description: "Ignored" },
+    ColumnInfo { name: "is_ignored", sql_type: "BOOLEAN", nullable: false, description: "Ignored (needs --include-ignored)" },
     ColumnInfo { name: "is_conflicted", sql_type: "BOOLEAN", nullable: false, description: "Conflicted" },
     ColumnInfo { name: "repo", sql_type: "TEXT", nullable: false, description: "Repository path" },
 ];
@@ -236,6 +401,102 @@ pub static NOTES_COLUMNS: &[ColumnInfo] = &[
     ColumnInfo { name: "repo", sql_type: "TEXT", nullable: false, description: "Repository path" },
 ];
 
+// ============================================================================
+// STORAGE TABLES
+// ============================================================================
+
+pub static PACKFILES_COLUMNS: &[ColumnInfo] = &[
+    ColumnInfo { name: "name", sql_type: "TEXT", nullable: false, description: "Pack file name" },
+    ColumnInfo { name: "path", sql_type: "TEXT", nullable: false, description: "Full path" },
+    ColumnInfo { name: "size", sql_type: "INTEGER", nullable: false, description: "Pack size in bytes" },
+    ColumnInfo { name: "object_count", sql_type: "INTEGER", nullable: true, description: "Objects in pack" },
+    ColumnInfo { name: "has_idx", sql_type: "BOOLEAN", nullable: false, description: "Has .idx index" },
+    ColumnInfo { name: "has_bitmap", sql_type: "BOOLEAN", nullable: false, description: "Has .bitmap reachability index" },
+    ColumnInfo { name: "has_rev", sql_type: "BOOLEAN", nullable: false, description: "Has .rev reverse index" },
+    ColumnInfo { name: "has_keep", sql_type: "BOOLEAN", nullable: false, description: "Has .keep marker" },
+    ColumnInfo { name: "modified_at", sql_type: "DATETIME", nullable: true, description: "Last modification time" },
+    ColumnInfo { name: "age_days", sql_type: "INTEGER", nullable: true, description: "Days since last modification" },
+    ColumnInfo { name: "repo", sql_type: "TEXT", nullable: false, description: "Repository path" },
+];
+
+pub static ODB_STATS_COLUMNS: &[ColumnInfo] = &[
+    ColumnInfo { name: "loose_count", sql_type: "INTEGER", nullable: false, description: "Loose objects" },
+    ColumnInfo { name: "loose_size", sql_type: "INTEGER", nullable: false, description: "Loose objects size in bytes" },
+    ColumnInfo { name: "packed_count", sql_type: "INTEGER", nullable: false, description: "Objects in packs" },
+    ColumnInfo { name: "pack_count", sql_type: "INTEGER", nullable: false, description: "Number of packs" },
+    ColumnInfo { name: "packed_size", sql_type: "INTEGER", nullable: false, description: "Packs size in bytes" },
+    ColumnInfo { name: "prune_packable", sql_type: "INTEGER", nullable: false, description: "Loose objects also in a pack" },
+    ColumnInfo { name: "garbage_count", sql_type: "INTEGER", nullable: false, description: "Stray files in object store" },
+    ColumnInfo { name: "garbage_size", sql_type: "INTEGER", nullable: false, description: "Stray files size in bytes" },
+    ColumnInfo { name: "repo", sql_type: "TEXT", nullable: false, description: "Repository path" },
+];
+
+pub static OBJECTS_COLUMNS: &[ColumnInfo] = &[
+    ColumnInfo { name: "id", sql_type: "TEXT", nullable: false, description: "Object SHA" },
+    ColumnInfo { name: "type", sql_type: "TEXT", nullable: false, description: "commit/tree/blob/tag" },
+    ColumnInfo { name: "size", sql_type: "INTEGER", nullable: false, description: "Uncompressed size in bytes" },
+    ColumnInfo { name: "is_reachable", sql_type: "BOOLEAN", nullable: false, description: "Reachable from a ref or reflog" },
+    ColumnInfo { name: "reachable_from", sql_type: "TEXT", nullable: true, description: "First ref or reflog entry reaching it" },
+    ColumnInfo { name: "repo", sql_type: "TEXT", nullable: false, description: "Repository path" },
+];
+
+// ============================================================================
+// COMPUTED TABLES
+// ============================================================================
+
+pub static FILE_COUPLING_COLUMNS: &[ColumnInfo] = &[
+    ColumnInfo { name: "path", sql_type: "TEXT", nullable: false, description: "File path" },
+    ColumnInfo { name: "coupled_path", sql_type: "TEXT", nullable: false, description: "Path changed in the same commits" },
+    ColumnInfo { name: "co_changes", sql_type: "INTEGER", nullable: false, description: "Commits changing both paths" },
+    ColumnInfo { name: "path_changes", sql_type: "INTEGER", nullable: false, description: "Commits changing path" },
+    ColumnInfo { name: "coupled_changes", sql_type: "INTEGER", nullable: false, description: "Commits changing coupled_path" },
+    ColumnInfo { name: "support", sql_type: "REAL", nullable: false, description: "Share of all counted commits changing both" },
+    ColumnInfo { name: "confidence", sql_type: "REAL", nullable: false, description: "Share of path's commits also changing coupled_path" },
+    ColumnInfo { name: "repo", sql_type: "TEXT", nullable: false, description: "Repository path" },
+];
+
+pub static FILE_STATS_COLUMNS: &[ColumnInfo] = &[
+    ColumnInfo { name: "path", sql_type: "TEXT", nullable: false, description: "Latest path, following renames" },
+    ColumnInfo { name: "commits", sql_type: "INTEGER", nullable: false, description: "Non-merge commits changing the file" },
+    ColumnInfo { name: "authors", sql_type: "INTEGER", nullable: false, description: "Distinct author emails" },
+    ColumnInfo { name: "insertions", sql_type: "INTEGER", nullable: false, description: "Lines added over history" },
+    ColumnInfo { name: "deletions", sql_type: "INTEGER", nullable: false, description: "Lines removed over history" },
+    ColumnInfo { name: "first_commit_id", sql_type: "TEXT", nullable: false, description: "Oldest commit changing the file" },
+    ColumnInfo { name: "first_changed_at", sql_type: "DATETIME", nullable: false, description: "When it was first changed" },
+    ColumnInfo { name: "last_commit_id", sql_type: "TEXT", nullable: false, description: "Newest commit changing the file" },
+    ColumnInfo { name: "last_changed_at", sql_type: "DATETIME", nullable: false, description: "When it was last changed" },
+    ColumnInfo { name: "size", sql_type: "INTEGER", nullable: true, description: "Size at HEAD (LFS-resolved), NULL if gone" },
+    ColumnInfo { name: "line_count", sql_type: "INTEGER", nullable: true, description: "Lines at HEAD, NULL if gone or binary" },
+    ColumnInfo { name: "repo", sql_type: "TEXT", nullable: false, description: "Repository path" },
+];
+
+pub static FILE_OWNERSHIP_COLUMNS: &[ColumnInfo] = &[
+    ColumnInfo { name: "path", sql_type: "TEXT", nullable: false, description: "File or directory path (. for the root)" },
+    ColumnInfo { name: "kind", sql_type: "TEXT", nullable: false, description: "file/directory" },
+    ColumnInfo { name: "author_name", sql_type: "TEXT", nullable: false, description: "Author name (mailmap-resolved)" },
+    ColumnInfo { name: "author_email", sql_type: "TEXT", nullable: false, description: "Author email (mailmap-resolved)" },
+    ColumnInfo { name: "lines", sql_type: "INTEGER", nullable: false, description: "Lines at HEAD last changed by the author" },
+    ColumnInfo { name: "total_lines", sql_type: "INTEGER", nullable: false, description: "Lines at HEAD under path" },
+    ColumnInfo { name: "share", sql_type: "REAL", nullable: false, description: "lines / total_lines" },
+    ColumnInfo { name: "is_primary", sql_type: "BOOLEAN", nullable: false, description: "Author with the most lines" },
+    ColumnInfo { name: "bus_factor", sql_type: "INTEGER", nullable: false, description: "Fewest authors holding half the lines" },
+    ColumnInfo { name: "repo", sql_type: "TEXT", nullable: false, description: "Repository path" },
+];
+
+pub static TODOS_COLUMNS: &[ColumnInfo] = &[
+    ColumnInfo { name: "path", sql_type: "TEXT", nullable: false, description: "File path" },
+    ColumnInfo { name: "line_number", sql_type: "INTEGER", nullable: false, description: "Line number" },
+    ColumnInfo { name: "tag", sql_type: "TEXT", nullable: false, description: "TODO/FIXME/HACK/XXX or a configured tag" },
+    ColumnInfo { name: "text", sql_type: "TEXT", nullable: false, description: "Text after the tag" },
+    ColumnInfo { name: "assignee", sql_type: "TEXT", nullable: true, description: "Name in TODO(name), if any" },
+    ColumnInfo { name: "commit_id", sql_type: "TEXT", nullable: true, description: "Commit that last changed the line" },
+    ColumnInfo { name: "author_name", sql_type: "TEXT", nullable: true, description: "Author (mailmap-resolved)" },
+    ColumnInfo { name: "author_email", sql_type: "TEXT", nullable: true, description: "Email (mailmap-resolved)" },
+    ColumnInfo { name: "authored_at", sql_type: "DATETIME", nullable: true, description: "When the line was written" },
+    ColumnInfo { name: "age_days", sql_type: "INTEGER", nullable: true, description: "Days since it was written" },
+    ColumnInfo { name: "repo", sql_type: "TEXT", nullable: false, description: "Repository path" },
+];
+
 // ============================================================================
 // ALL TABLES
 // ============================================================================
@@ -247,7 +508,7 @@ pub static TABLES: &[TableInfo] = &[
         description: "Commit history and metadata",
         category: "CORE",
         columns: COMMITS_COLUMNS,
-        create_sql: "CREATE TABLE IF NOT EXISTS commits (id TEXT NOT NULL, short_id TEXT NOT NULL, tree_id TEXT NOT NULL, author_name TEXT NOT NULL, author_email TEXT NOT NULL, authored_at TEXT NOT NULL, committer_name TEXT NOT NULL, committer_email TEXT NOT NULL, committed_at TEXT NOT NULL, message TEXT NOT NULL, summary TEXT NOT NULL, body TEXT, parent_count INTEGER NOT NULL, is_merge INTEGER NOT NULL, repo TEXT NOT NULL, PRIMARY KEY (id, repo))",
+        create_sql: "CREATE TABLE IF NOT EXISTS commits (id TEXT NOT NULL, short_id TEXT NOT NULL, tree_id TEXT NOT NULL, author_name TEXT NOT NULL, author_email TEXT NOT NULL, authored_at TEXT NOT NULL, committer_name TEXT NOT NULL, committer_email TEXT NOT NULL, committed_at TEXT NOT NULL, message TEXT NOT NULL, summary TEXT NOT NULL, body TEXT, parent_count INTEGER NOT NULL, is_merge INTEGER NOT NULL, is_shallow_boundary INTEGER NOT NULL, is_mainline INTEGER NOT NULL, mainline_depth INTEGER, merged_in_by TEXT, repo TEXT NOT NULL, PRIMARY KEY (id, repo))",
     },
     TableInfo {
         name: "commit_parents",
@@ -256,6 +517,27 @@ pub static TABLES: &[TableInfo] = &[
         columns: COMMIT_PARENTS_COLUMNS,
         create_sql: "CREATE TABLE IF NOT EXISTS commit_parents (commit_id TEXT NOT NULL, parent_id TEXT NOT NULL, parent_index INTEGER NOT NULL, repo TEXT NOT NULL, PRIMARY KEY (commit_id, parent_id, repo))",
     },
+    TableInfo {
+        name: "commit_graph",
+        description: "Topology metrics for each commit",
+        category: "CORE",
+        columns: COMMIT_GRAPH_COLUMNS,
+        create_sql: "CREATE TABLE IF NOT EXISTS commit_graph (commit_id TEXT NOT NULL, generation INTEGER NOT NULL, root_distance INTEGER NOT NULL, child_count INTEGER NOT NULL, is_branch_point INTEGER NOT NULL, is_tip INTEGER NOT NULL, merge_depth INTEGER, repo TEXT NOT NULL, PRIMARY KEY (commit_id, repo))",
+    },
+    TableInfo {
+        name: "commit_references",
+        description: "Pull request, issue and ticket references in messages",
+        category: "CORE",
+        columns: COMMIT_REFERENCES_COLUMNS,
+        create_sql: "CREATE TABLE IF NOT EXISTS commit_references (commit_id TEXT NOT NULL, kind TEXT NOT NULL, key TEXT NOT NULL, number INTEGER, source_branch TEXT, repo TEXT NOT NULL, PRIMARY KEY (commit_id, kind, key, repo))",
+    },
+    TableInfo {
+        name: "shallow",
+        description: "Shallow clone boundary and grafted commits",
+        category: "CORE",
+        columns: SHALLOW_COLUMNS,
+        create_sql: "CREATE TABLE IF NOT EXISTS shallow (commit_id TEXT NOT NULL, source TEXT NOT NULL, summary TEXT, committed_at TEXT, parent_count INTEGER, repo TEXT NOT NULL, PRIMARY KEY (commit_id, source, repo))",
+    },
     // REFERENCES
     TableInfo {
         name: "branches",
@@ -264,6 +546,13 @@ pub static TABLES: &[TableInfo] = &[
         columns: BRANCHES_COLUMNS,
         create_sql: "CREATE TABLE IF NOT EXISTS branches (name TEXT NOT NULL, full_name TEXT NOT NULL, target_id TEXT NOT NULL, is_remote INTEGER NOT NULL, is_head INTEGER NOT NULL, remote_name TEXT, upstream TEXT, ahead INTEGER, behind INTEGER, repo TEXT NOT NULL, PRIMARY KEY (full_name, repo))",
     },
+    TableInfo {
+        name: "branch_report",
+        description: "Branches compared to a base branch, with merge status",
+        category: "REFERENCES",
+        columns: BRANCH_REPORT_COLUMNS,
+        create_sql: "CREATE TABLE IF NOT EXISTS branch_report (name TEXT NOT NULL, full_name TEXT NOT NULL, is_remote INTEGER NOT NULL, base TEXT NOT NULL, merge_base_id TEXT, ahead INTEGER NOT NULL, behind INTEGER NOT NULL, is_merged INTEGER NOT NULL, merged_via TEXT, last_commit_id TEXT NOT NULL, last_commit_at TEXT NOT NULL, last_author_name TEXT NOT NULL, last_author_email TEXT NOT NULL, age_days INTEGER NOT NULL, repo TEXT NOT NULL, PRIMARY KEY (full_name, repo))",
+    },
     TableInfo {
         name: "tags",
         description: "Annotated and lightweight tags",
@@ -271,6 +560,20 @@ pub static TABLES: &[TableInfo] = &[
         columns: TAGS_COLUMNS,
         create_sql: "CREATE TABLE IF NOT EXISTS tags (name TEXT NOT NULL, full_name TEXT NOT NULL, target_id TEXT NOT NULL, target_type TEXT NOT NULL, is_annotated INTEGER NOT NULL, tagger_name TEXT, tagger_email TEXT, tagged_at TEXT, message TEXT, repo TEXT NOT NULL, PRIMARY KEY (full_name, repo))",
     },
+    TableInfo {
+        name: "commit_branches",
+        description: "Branches containing each commit",
+        category: "REFERENCES",
+        columns: COMMIT_BRANCHES_COLUMNS,
+        create_sql: "CREATE TABLE IF NOT EXISTS commit_branches (commit_id TEXT NOT NULL, branch TEXT NOT NULL, full_name TEXT NOT NULL, is_remote INTEGER NOT NULL, repo TEXT NOT NULL, PRIMARY KEY (commit_id, full_name, repo))",
+    },
+    TableInfo {
+        name: "commit_tags",
+        description: "Tags containing each commit",
+        category: "REFERENCES",
+        columns: COMMIT_TAGS_COLUMNS,
+        create_sql: "CREATE TABLE IF NOT EXISTS commit_tags (commit_id TEXT NOT NULL, tag TEXT NOT NULL, full_name TEXT NOT NULL, repo TEXT NOT NULL, PRIMARY KEY (commit_id, full_name, repo))",
+    },
     TableInfo {
         name: "refs",
         description: "All references (unified view)",
@@ -292,6 +595,20 @@ pub static TABLES: &[TableInfo] = &[
         columns: REFLOG_COLUMNS,
         create_sql: "CREATE TABLE IF NOT EXISTS reflog (ref_name TEXT NOT NULL, entry_index INTEGER NOT NULL, old_id TEXT NOT NULL, new_id TEXT NOT NULL, committer_name TEXT NOT NULL, committer_email TEXT NOT NULL, committed_at TEXT NOT NULL, message TEXT NOT NULL, action TEXT NOT NULL, repo TEXT NOT NULL, PRIMARY KEY (ref_name, entry_index, repo))",
     },
+    TableInfo {
+        name: "lost_commits",
+        description: "Commits no longer reachable from any ref",
+        category: "REFERENCES",
+        columns: LOST_COMMITS_COLUMNS,
+        create_sql: "CREATE TABLE IF NOT EXISTS lost_commits (commit_id TEXT NOT NULL, short_id TEXT NOT NULL, summary TEXT NOT NULL, author_name TEXT NOT NULL, author_email TEXT NOT NULL, authored_at TEXT NOT NULL, source TEXT NOT NULL, last_ref TEXT, last_seen_at TEXT, is_tip INTEGER NOT NULL, repo TEXT NOT NULL, PRIMARY KEY (commit_id, repo))",
+    },
+    TableInfo {
+        name: "replace_refs",
+        description: "Object replacements (refs/replace, not applied)",
+        category: "REFERENCES",
+        columns: REPLACE_REFS_COLUMNS,
+        create_sql: "CREATE TABLE IF NOT EXISTS replace_refs (original_id TEXT NOT NULL, replacement_id TEXT NOT NULL, original_type TEXT, replacement_type TEXT, repo TEXT NOT NULL, PRIMARY KEY (original_id, repo))",
+    },
     // CHANGES
     TableInfo {
         name: "diffs",
@@ -305,14 +622,42 @@ pub static TABLES: &[TableInfo] = &[
         description: "Per-file changes",
         category: "CHANGES",
         columns: DIFF_FILES_COLUMNS,
-        create_sql: "CREATE TABLE IF NOT EXISTS diff_files (commit_id TEXT NOT NULL, parent_id TEXT, old_path TEXT, new_path TEXT, status TEXT NOT NULL, insertions INTEGER NOT NULL, deletions INTEGER NOT NULL, is_binary INTEGER NOT NULL, similarity INTEGER, repo TEXT NOT NULL)",
+        create_sql: "CREATE TABLE IF NOT EXISTS diff_files (commit_id TEXT NOT NULL, parent_id TEXT, old_path TEXT, new_path TEXT, status TEXT NOT NULL, insertions INTEGER NOT NULL, deletions INTEGER NOT NULL, is_binary INTEGER NOT NULL, similarity INTEGER, is_lfs INTEGER NOT NULL, old_size INTEGER, new_size INTEGER, language TEXT, category TEXT, repo TEXT NOT NULL)",
+    },
+    TableInfo {
+        name: "patch_ids",
+        description: "Patch-ids for spotting cherry-picks and rebased copies",
+        category: "CHANGES",
+        columns: PATCH_IDS_COLUMNS,
+        create_sql: "CREATE TABLE IF NOT EXISTS patch_ids (commit_id TEXT NOT NULL, patch_id TEXT NOT NULL, repo TEXT NOT NULL, PRIMARY KEY (commit_id, repo))",
+    },
+    TableInfo {
+        name: "reverts",
+        description: "Commits that undo earlier commits",
+        category: "CHANGES",
+        columns: REVERTS_COLUMNS,
+        create_sql: "CREATE TABLE IF NOT EXISTS reverts (commit_id TEXT NOT NULL, reverted_id TEXT NOT NULL, detected_by TEXT NOT NULL, patch_matches INTEGER NOT NULL, depth INTEGER NOT NULL, repo TEXT NOT NULL, PRIMARY KEY (commit_id, repo))",
     },
     TableInfo {
         name: "blame",
         description: "Per-line attribution",
         category: "CHANGES",
         columns: BLAME_COLUMNS,
-        create_sql: "CREATE TABLE IF NOT EXISTS blame (path TEXT NOT NULL, line_number INTEGER NOT NULL, commit_id TEXT NOT NULL, original_line INTEGER NOT NULL, original_path TEXT NOT NULL, author_name TEXT NOT NULL, author_email TEXT NOT NULL, authored_at TEXT NOT NULL, line_content TEXT NOT NULL, repo TEXT NOT NULL, PRIMARY KEY (path, line_number, repo))",
+        create_sql: "CREATE TABLE IF NOT EXISTS blame (path TEXT NOT NULL, line_number INTEGER NOT NULL, commit_id TEXT NOT NULL, original_line INTEGER NOT NULL, original_path TEXT NOT NULL, author_name TEXT NOT NULL, author_email TEXT NOT NULL, authored_at TEXT NOT NULL, line_content TEXT NOT NULL, language TEXT, category TEXT NOT NULL, repo TEXT NOT NULL, PRIMARY KEY (path, line_number, repo))",
+    },
+    TableInfo {
+        name: "lfs_objects",
+        description: "Git LFS pointers written by each commit",
+        category: "CHANGES",
+        columns: LFS_OBJECTS_COLUMNS,
+        create_sql: "CREATE TABLE IF NOT EXISTS lfs_objects (commit_id TEXT NOT NULL, path TEXT NOT NULL, status TEXT NOT NULL, pointer_id TEXT NOT NULL, lfs_oid TEXT NOT NULL, size INTEGER NOT NULL, is_present INTEGER NOT NULL, in_head INTEGER NOT NULL, repo TEXT NOT NULL, PRIMARY KEY (commit_id, path, repo))",
+    },
+    TableInfo {
+        name: "submodule_updates",
+        description: "Submodule pointer changes per commit",
+        category: "CHANGES",
+        columns: SUBMODULE_UPDATES_COLUMNS,
+        create_sql: "CREATE TABLE IF NOT EXISTS submodule_updates (commit_id TEXT NOT NULL, path TEXT NOT NULL, old_id TEXT, new_id TEXT, change TEXT NOT NULL, direction TEXT, commits_added INTEGER, commits_removed INTEGER, repo TEXT NOT NULL, PRIMARY KEY (commit_id, path, repo))",
     },
     // CONFIGURATION
     TableInfo {
@@ -334,7 +679,28 @@ pub static TABLES: &[TableInfo] = &[
         description: "Nested repositories",
         category: "CONFIGURATION",
         columns: SUBMODULES_COLUMNS,
-        create_sql: "CREATE TABLE IF NOT EXISTS submodules (name TEXT NOT NULL, path TEXT NOT NULL, url TEXT NOT NULL, branch TEXT, head_id TEXT, status TEXT NOT NULL, repo TEXT NOT NULL, PRIMARY KEY (name, repo))",
+        create_sql: "CREATE TABLE IF NOT EXISTS submodules (name TEXT NOT NULL, path TEXT NOT NULL, url TEXT NOT NULL, branch TEXT, head_id TEXT, status TEXT NOT NULL, submodule_repo TEXT, repo TEXT NOT NULL, PRIMARY KEY (name, repo))",
+    },
+    TableInfo {
+        name: "gitattributes",
+        description: "Rules from .gitattributes and attributes files",
+        category: "CONFIGURATION",
+        columns: GITATTRIBUTES_COLUMNS,
+        create_sql: "CREATE TABLE IF NOT EXISTS gitattributes (source TEXT NOT NULL, scope TEXT NOT NULL, line_number INTEGER NOT NULL, pattern TEXT NOT NULL, attribute TEXT NOT NULL, value TEXT NOT NULL, repo TEXT NOT NULL)",
+    },
+    TableInfo {
+        name: "gitignore_rules",
+        description: "Rules from .gitignore and exclude files",
+        category: "CONFIGURATION",
+        columns: GITIGNORE_RULES_COLUMNS,
+        create_sql: "CREATE TABLE IF NOT EXISTS gitignore_rules (source TEXT NOT NULL, scope TEXT NOT NULL, line_number INTEGER NOT NULL, pattern TEXT NOT NULL, is_negated INTEGER NOT NULL, is_dir_only INTEGER NOT NULL, precedence INTEGER NOT NULL, repo TEXT NOT NULL)",
+    },
+    TableInfo {
+        name: "codeowners",
+        description: "Rules from the CODEOWNERS file",
+        category: "CONFIGURATION",
+        columns: CODEOWNERS_COLUMNS,
+        create_sql: "CREATE TABLE IF NOT EXISTS codeowners (source TEXT NOT NULL, line_number INTEGER NOT NULL, pattern TEXT NOT NULL, owners TEXT, owner_count INTEGER NOT NULL, repo TEXT NOT NULL, PRIMARY KEY (line_number, repo))",
     },
     // WORKING DIRECTORY
     TableInfo {
@@ -366,6 +732,57 @@ pub static TABLES: &[TableInfo] = &[
         columns: NOTES_COLUMNS,
         create_sql: "CREATE TABLE IF NOT EXISTS notes (notes_ref TEXT NOT NULL, target_id TEXT NOT NULL, note_id TEXT NOT NULL, content TEXT NOT NULL, repo TEXT NOT NULL, PRIMARY KEY (notes_ref, target_id, repo))",
     },
+    // STORAGE
+    TableInfo {
+        name: "packfiles",
+        description: "Packfiles in the object database",
+        category: "STORAGE",
+        columns: PACKFILES_COLUMNS,
+        create_sql: "CREATE TABLE IF NOT EXISTS packfiles (name TEXT NOT NULL, path TEXT NOT NULL, size INTEGER NOT NULL, object_count INTEGER, has_idx INTEGER NOT NULL, has_bitmap INTEGER NOT NULL, has_rev INTEGER NOT NULL, has_keep INTEGER NOT NULL, modified_at TEXT, age_days INTEGER, repo TEXT NOT NULL, PRIMARY KEY (name, repo))",
+    },
+    TableInfo {
+        name: "odb_stats",
+        description: "Object database statistics (count-objects -v)",
+        category: "STORAGE",
+        columns: ODB_STATS_COLUMNS,
+        create_sql: "CREATE TABLE IF NOT EXISTS odb_stats (loose_count INTEGER NOT NULL, loose_size INTEGER NOT NULL, packed_count INTEGER NOT NULL, pack_count INTEGER NOT NULL, packed_size INTEGER NOT NULL, prune_packable INTEGER NOT NULL, garbage_count INTEGER NOT NULL, garbage_size INTEGER NOT NULL, repo TEXT NOT NULL, PRIMARY KEY (repo))",
+    },
+    TableInfo {
+        name: "objects",
+        description: "Every object in the database, including unreachable ones",
+        category: "STORAGE",
+        columns: OBJECTS_COLUMNS,
+        create_sql: "CREATE TABLE IF NOT EXISTS objects (id TEXT NOT NULL, type TEXT NOT NULL, size INTEGER NOT NULL, is_reachable INTEGER NOT NULL, reachable_from TEXT, repo TEXT NOT NULL, PRIMARY KEY (id, repo))",
+    },
+    // COMPUTED
+    TableInfo {
+        name: "file_coupling",
+        description: "Pairs of files that change in the same commits",
+        category: "COMPUTED",
+        columns: FILE_COUPLING_COLUMNS,
+        create_sql: "CREATE TABLE IF NOT EXISTS file_coupling (path TEXT NOT NULL, coupled_path TEXT NOT NULL, co_changes INTEGER NOT NULL, path_changes INTEGER NOT NULL, coupled_changes INTEGER NOT NULL, support REAL NOT NULL, confidence REAL NOT NULL, repo TEXT NOT NULL, PRIMARY KEY (path, coupled_path, repo))",
+    },
+    TableInfo {
+        name: "file_stats",
+        description: "Churn, authors and size per file over history",
+        category: "COMPUTED",
+        columns: FILE_STATS_COLUMNS,
+        create_sql: "CREATE TABLE IF NOT EXISTS file_stats (path TEXT NOT NULL, commits INTEGER NOT NULL, authors INTEGER NOT NULL, insertions INTEGER NOT NULL, deletions INTEGER NOT NULL, first_commit_id TEXT NOT NULL, first_changed_at TEXT NOT NULL, last_commit_id TEXT NOT NULL, last_changed_at TEXT NOT NULL, size INTEGER, line_count INTEGER, repo TEXT NOT NULL, PRIMARY KEY (path, repo))",
+    },
+    TableInfo {
+        name: "file_ownership",
+        description: "Authors' share of the surviving lines per file and directory",
+        category: "COMPUTED",
+        columns: FILE_OWNERSHIP_COLUMNS,
+        create_sql: "CREATE TABLE IF NOT EXISTS file_ownership (path TEXT NOT NULL, kind TEXT NOT NULL, author_name TEXT NOT NULL, author_email TEXT NOT NULL, lines INTEGER NOT NULL, total_lines INTEGER NOT NULL, share REAL NOT NULL, is_primary INTEGER NOT NULL, bus_factor INTEGER NOT NULL, repo TEXT NOT NULL, PRIMARY KEY (path, kind, author_email, repo))",
+    },
+    TableInfo {
+        name: "todos",
+        description: "TODO, FIXME, HACK and XXX comments with their blame",
+        category: "COMPUTED",
+        columns: TODOS_COLUMNS,
+        create_sql: "CREATE TABLE IF NOT EXISTS todos (path TEXT NOT NULL, line_number INTEGER NOT NULL, tag TEXT NOT NULL, text TEXT NOT NULL, assignee TEXT, commit_id TEXT, author_name TEXT, author_email TEXT, authored_at TEXT, age_days INTEGER, repo TEXT NOT NULL, PRIMARY KEY (path, line_number, repo))",
+    },
 ];
 
 pub fn get_table_info(name: &str) -> Option<&'static TableInfo> {
@@ -0,0 +1,52 @@
+mod common;
+
+use common::{git, repo_with, vcsql};
+use std::path::Path;
+
+fn merged_via(dir: &Path, branch: &str) -> String {
+    let sql = format!(
+        "SELECT merged_via FROM branch_report WHERE name = '{}'",
+        branch
+    );
+    vcsql(dir, &["--base", "main"], &sql)
+}
+
+/// A `topic` branch that drops the trailing newline of `a.txt` and makes
+/// `run.sh` executable, while `main` moves on with an unrelated commit.
+fn repo_with_topic() -> tempfile::TempDir {
+    let dir = repo_with("a.txt", "one\ntwo\n");
+    let path = dir.path();
+    std::fs::write(path.join("run.sh"), "echo hi\n").unwrap();
+    git(path, &["add", "."]);
+    git(path, &["commit", "-qm", "add script"]);
+
+    git(path, &["checkout", "-qb", "topic"]);
+    std::fs::write(path.join("a.txt"), "one\ntwo").unwrap();
+    git(path, &["commit", "-qam", "drop trailing newline"]);
+    git(path, &["update-index", "--chmod=+x", "run.sh"]);
+    git(path, &["commit", "-qm", "make executable"]);
+    git(path, &["checkout", "-q", "--", "run.sh"]);
+
+    git(path, &["checkout", "-q", "main"]);
+    std::fs::write(path.join("b.txt"), "other\n").unwrap();
+    git(path, &["add", "."]);
+    git(path, &["commit", "-qm", "unrelated"]);
+    dir
+}
+
+#[test]
+fn squash_merge_is_detected() {
+    let dir = repo_with_topic();
+    git(dir.path(), &["merge", "-q", "--squash", "topic"]);
+    git(dir.path(), &["commit", "-qm", "squashed topic"]);
+
+    assert_eq!(merged_via(dir.path(), "topic"), "squash");
+}
+
+#[test]
+fn cherry_picks_are_detected() {
+    let dir = repo_with_topic();
+    git(dir.path(), &["cherry-pick", "main..topic"]);
+
+    assert_eq!(merged_via(dir.path(), "topic"), "cherry-pick");
+}
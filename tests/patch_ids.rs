@@ -0,0 +1,43 @@
+mod common;
+
+use common::{git, repo_with, vcsql};
+use std::path::Path;
+use std::process::Command;
+
+/// `git patch-id --stable` of HEAD's change.
+fn git_patch_id(dir: &Path) -> String {
+    let output = Command::new("sh")
+        .args(["-c", "git show HEAD | git patch-id --stable"])
+        .current_dir(dir)
+        .output()
+        .expect("git patch-id runs");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    stdout.split_whitespace().next().unwrap().to_string()
+}
+
+fn vcsql_patch_id(dir: &Path) -> String {
+    let head = git(dir, &["rev-parse", "HEAD"]);
+    let sql = format!(
+        "SELECT patch_id FROM patch_ids WHERE commit_id = '{}'",
+        head.trim()
+    );
+    vcsql(dir, &[], &sql)
+}
+
+#[test]
+fn patch_id_matches_git_without_trailing_newline() {
+    let dir = repo_with("a.txt", "one\ntwo");
+    std::fs::write(dir.path().join("a.txt"), "one\nthree").unwrap();
+    git(dir.path(), &["commit", "-qam", "change last line"]);
+
+    assert_eq!(vcsql_patch_id(dir.path()), git_patch_id(dir.path()));
+}
+
+#[test]
+fn patch_id_matches_git_for_mode_change() {
+    let dir = repo_with("run.sh", "echo hi\n");
+    git(dir.path(), &["update-index", "--chmod=+x", "run.sh"]);
+    git(dir.path(), &["commit", "-qm", "make executable"]);
+
+    assert_eq!(vcsql_patch_id(dir.path()), git_patch_id(dir.path()));
+}
@@ -0,0 +1,43 @@
+mod common;
+
+use common::{git, repo_with, vcsql};
+use std::path::Path;
+
+/// Undo HEAD with a commit whose message doesn't name it, so only the
+/// patch-id can pair the two.
+fn undo_head(dir: &Path) -> (String, String) {
+    let target = git(dir, &["rev-parse", "HEAD"]).trim().to_string();
+    git(dir, &["revert", "--no-commit", "HEAD"]);
+    git(dir, &["commit", "-qm", "undo"]);
+    let undo = git(dir, &["rev-parse", "HEAD"]).trim().to_string();
+    (undo, target)
+}
+
+fn reverts(dir: &Path) -> String {
+    vcsql(
+        dir,
+        &[],
+        "SELECT commit_id, reverted_id, detected_by FROM reverts",
+    )
+}
+
+#[test]
+fn revert_pairs_change_without_trailing_newline() {
+    let dir = repo_with("a.txt", "one\ntwo");
+    std::fs::write(dir.path().join("a.txt"), "one\nthree").unwrap();
+    git(dir.path(), &["commit", "-qam", "change last line"]);
+    let (undo, target) = undo_head(dir.path());
+
+    assert_eq!(reverts(dir.path()), format!("{},{},patch-id", undo, target));
+}
+
+#[test]
+fn revert_pairs_mode_change() {
+    let dir = repo_with("run.sh", "echo hi\n");
+    git(dir.path(), &["update-index", "--chmod=+x", "run.sh"]);
+    git(dir.path(), &["commit", "-qm", "make executable"]);
+    git(dir.path(), &["checkout", "-q", "--", "run.sh"]);
+    let (undo, target) = undo_head(dir.path());
+
+    assert_eq!(reverts(dir.path()), format!("{},{},patch-id", undo, target));
+}